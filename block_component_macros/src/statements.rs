@@ -143,8 +143,17 @@ impl ToTokens for PropertyDeclaration {
             quote! {}
         };
 
+        let skip_none = if self.modifiers.contains(&Modifier::SkipNone) {
+            quote! {
+                #[serde(skip_serializing_if = "Option::is_none")]
+            }
+        } else {
+            quote! {}
+        };
+
         tokens.append_all(quote! {
-            #[serde(rename(serialize = #property_id))]
+            #[serde(rename = #property_id)]
+            #skip_none
             #pub_or_not #name: #data_type,
         });
     }
@@ -192,10 +201,12 @@ impl ToTokens for ComponentData {
 
         let impl_stmt = quote! {
             impl BlockComponent for #name {
-                fn serialize(&self) -> String {
-                    let value = format!("\"{}\": {}", #prop_id, serde_json::to_string_pretty(&self).unwrap());
+                fn id(&self) -> String {
+                    #prop_id.to_string()
+                }
 
-                    value
+                fn value(&self) -> serde_json::Value {
+                    serde_json::to_value(self).unwrap()
                 }
             }
 
@@ -230,7 +241,7 @@ impl ToTokens for ComponentData {
         
 
         let tks: TokenStream = quote! {
-            #[derive(serde::Serialize, Debug, Clone)]
+            #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
             #[serde(rename = #prop_id)]
             #additional_derives
             pub struct #name {
@@ -308,6 +319,10 @@ impl ToTokens for NewFun {
             let tp = &self.types[idx];
             let modifiers = &self.modifiers[idx];
 
+            if modifiers.contains(&Modifier::SetterOnly) {
+                continue;
+            }
+
             arg_arr.push(NewFunArgPair {
                 name: arg.clone(),
                 data_type: tp.clone(),
@@ -378,7 +393,11 @@ impl ToTokens for NewFunSetPair {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let name = self.name.clone();
 
-        let tks: TokenStream = if self.modifiers.contains(&Modifier::IntoModifier) {
+        let tks: TokenStream = if self.modifiers.contains(&Modifier::SetterOnly) {
+            quote! {
+                #name: Default::default()
+            }
+        } else if self.modifiers.contains(&Modifier::IntoModifier) {
             quote! {
                 #name: #name.into()
             }
@@ -398,7 +417,9 @@ enum Modifier {
     Undetermined,
     Public,
     Transparency,
-    UsingFn
+    UsingFn,
+    SkipNone,
+    SetterOnly
 }
 
 impl Parse for Modifier {
@@ -420,6 +441,8 @@ impl Parse for Modifier {
             "public" => Ok(Modifier::Public),
             "using" => Ok(Modifier::UsingFn),
             "transparency" => Ok(Modifier::Transparency),
+            "skip_none" => Ok(Modifier::SkipNone),
+            "setter_only" => Ok(Modifier::SetterOnly),
             _ => Ok(Modifier::Undetermined)
         }
     }
@@ -0,0 +1,89 @@
+use crate::import::pack_files;
+use crate::pack::PackPart;
+use serde_json::Value;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Where the contents of a pack asset come from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AssetSource {
+    /// A file copied as is.
+    File(PathBuf),
+    /// A directory whose contents are copied below the asset path.
+    Directory(PathBuf),
+    Text(String),
+    /// Written as pretty-printed JSON.
+    Json(Value),
+}
+
+/// A file, or directory of files, written into the generated pack as is.
+#[derive(Clone, Debug)]
+pub struct PackAsset {
+    pub part: PackPart,
+    /// Path relative to the BP or RP root, e.g. `ui/hud_screen.json`.
+    pub path: String,
+    pub source: AssetSource,
+}
+
+impl PackAsset {
+    pub fn new(part: PackPart, path: impl Into<String>, source: AssetSource) -> Self {
+        Self {
+            part,
+            path: path.into(),
+            source,
+        }
+    }
+
+    /// Whether the path stays inside the pack part, i.e. is relative and has
+    /// no `..` components.
+    pub fn has_valid_path(&self) -> bool {
+        !self.path.is_empty()
+            && Path::new(&self.path)
+                .components()
+                .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+    }
+
+    /// Writes the asset below the given pack part root.
+    pub fn write(&self, root: &Path) -> std::io::Result<()> {
+        let target = root.join(&self.path);
+
+        match &self.source {
+            AssetSource::File(src) => copy_file(src, &target),
+            AssetSource::Directory(src) => {
+                if !src.is_dir() {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::NotFound,
+                        format!("\"{}\" isn't a directory", src.display()),
+                    ));
+                }
+
+                for file in pack_files(src) {
+                    copy_file(&src.join(&file), &target.join(&file))?;
+                }
+
+                Ok(())
+            }
+            AssetSource::Text(text) => write_file(&target, text),
+            AssetSource::Json(value) => write_file(&target, &serde_json::to_string_pretty(value)?),
+        }
+    }
+}
+
+fn create_parent(target: &Path) -> std::io::Result<()> {
+    match target.parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
+}
+
+fn copy_file(src: &Path, target: &Path) -> std::io::Result<()> {
+    create_parent(target)?;
+
+    fs::copy(src, target).map(|_| ())
+}
+
+fn write_file(target: &Path, contents: &str) -> std::io::Result<()> {
+    create_parent(target)?;
+
+    fs::write(target, contents)
+}
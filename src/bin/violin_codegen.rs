@@ -0,0 +1,40 @@
+//! Generates Violin source code from an existing addon.
+//!
+//! `violin_codegen <BP folder> <RP folder> [output.rs]`
+
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+use violin_rs::codegen::generate_pack_source;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (bp_dir, rp_dir, output) = match args.as_slice() {
+        [bp_dir, rp_dir] => (bp_dir, rp_dir, None),
+        [bp_dir, rp_dir, output] => (bp_dir, rp_dir, Some(output)),
+        _ => {
+            eprintln!("Usage: violin_codegen <BP folder> <RP folder> [output.rs]");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let source = match generate_pack_source(bp_dir, rp_dir) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("Cannot read the pack: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match output {
+        Some(output) => {
+            if let Err(err) = fs::write(output, source) {
+                eprintln!("Cannot write \"{}\": {}", output, err);
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{}", source),
+    }
+
+    ExitCode::SUCCESS
+}
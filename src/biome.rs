@@ -0,0 +1,232 @@
+use crate::vio::{Identifier, RGBColor, SemVer};
+use derive_setters::Setters;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+#[derive(Clone, Debug, Serialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct BiomeClimate {
+    pub temperature: f64,
+    pub downfall: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub snow_accumulation: Option<[f64; 2]>,
+}
+
+impl BiomeClimate {
+    pub fn new(temperature: f64, downfall: f64) -> Self {
+        Self {
+            temperature,
+            downfall,
+            snow_accumulation: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct BiomeSurfaceParameters {
+    pub top_material: Identifier,
+    pub mid_material: Identifier,
+    pub foundation_material: Identifier,
+    pub sea_floor_material: Identifier,
+    pub sea_material: Identifier,
+    pub sea_floor_depth: i32,
+}
+
+impl BiomeSurfaceParameters {
+    pub fn new(
+        top_material: Identifier,
+        mid_material: Identifier,
+        foundation_material: Identifier,
+    ) -> Self {
+        Self {
+            top_material,
+            mid_material,
+            foundation_material,
+            sea_floor_material: Identifier::new("minecraft", "gravel"),
+            sea_material: Identifier::new("minecraft", "water"),
+            sea_floor_depth: 7,
+        }
+    }
+
+    pub fn using_sea(self, sea_material: Identifier, sea_floor_material: Identifier, sea_floor_depth: i32) -> Self {
+        Self {
+            sea_material,
+            sea_floor_material,
+            sea_floor_depth,
+            ..self
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BiomeClimateCategory {
+    Medium,
+    Warm,
+    Lukewarm,
+    Cold,
+    Frozen,
+}
+
+#[derive(Clone, Debug, Serialize, Default, Setters)]
+#[setters(prefix = "using_")]
+pub struct BiomeOverworldGenerationRules {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub hills_transformation: Option<Identifier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub mutate_transformation: Option<Identifier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub river_transformation: Option<Identifier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub shore_transformation: Option<Identifier>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub generate_for_climates: Vec<(BiomeClimateCategory, i32)>,
+}
+
+impl BiomeOverworldGenerationRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum BiomeOverworldHeight {
+    NoiseType { noise_type: String },
+    NoiseParams { noise_params: [f64; 2] },
+}
+
+/// Client side biome data, written to `RP/biomes_client.json`.
+#[derive(Clone, Debug, Default, Setters)]
+#[setters(prefix = "using_", strip_option)]
+pub struct ClientBiome {
+    pub fog_identifier: Option<Identifier>,
+    pub water_surface_color: Option<RGBColor>,
+    pub water_fog_color: Option<RGBColor>,
+    pub water_surface_transparency: Option<f64>,
+    pub water_fog_distance: Option<i32>,
+    pub sky_color: Option<RGBColor>,
+}
+
+impl ClientBiome {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn serialize(&self) -> Value {
+        let mut map = Map::new();
+
+        if let Some(fog) = &self.fog_identifier {
+            map.insert("fog_identifier".to_string(), json!(fog.render()));
+        }
+        if let Some(color) = &self.water_surface_color {
+            map.insert("water_surface_color".to_string(), json!(color.render_as_hex()));
+        }
+        if let Some(color) = &self.water_fog_color {
+            map.insert("water_fog_color".to_string(), json!(color.render_as_hex()));
+        }
+        if let Some(transparency) = self.water_surface_transparency {
+            map.insert("water_surface_transparency".to_string(), json!(transparency));
+        }
+        if let Some(distance) = self.water_fog_distance {
+            map.insert("water_fog_distance".to_string(), json!(distance));
+        }
+        if let Some(color) = &self.sky_color {
+            map.insert("sky_color".to_string(), json!(color.render_as_hex()));
+        }
+
+        Value::Object(map)
+    }
+}
+
+/// A custom biome. The behavior part is written to `BP/biomes`, the client
+/// part (if any) is merged into `RP/biomes_client.json`.
+#[derive(Clone, Debug, Setters)]
+#[setters(prefix = "using_")]
+pub struct Biome {
+    #[setters(skip)]
+    id: Identifier,
+    pub format_version: SemVer,
+    #[setters(strip_option)]
+    pub climate: Option<BiomeClimate>,
+    #[setters(strip_option)]
+    pub surface_parameters: Option<BiomeSurfaceParameters>,
+    #[setters(strip_option)]
+    pub overworld_generation_rules: Option<BiomeOverworldGenerationRules>,
+    #[setters(strip_option)]
+    pub overworld_height: Option<BiomeOverworldHeight>,
+    pub tags: Vec<String>,
+    #[setters(strip_option)]
+    pub client: Option<ClientBiome>,
+}
+
+impl Biome {
+    pub fn new(id: Identifier) -> Self {
+        Self {
+            id,
+            format_version: SemVer::current(),
+            climate: None,
+            surface_parameters: None,
+            overworld_generation_rules: None,
+            overworld_height: None,
+            tags: vec![],
+            client: None,
+        }
+    }
+
+    pub fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut components = Map::new();
+
+        if let Some(climate) = &self.climate {
+            components.insert("minecraft:climate".to_string(), json!(climate));
+        }
+        if let Some(surface) = &self.surface_parameters {
+            components.insert("minecraft:surface_parameters".to_string(), json!(surface));
+        }
+        if let Some(rules) = &self.overworld_generation_rules {
+            components.insert(
+                "minecraft:overworld_generation_rules".to_string(),
+                json!(rules),
+            );
+        }
+        if let Some(height) = &self.overworld_height {
+            components.insert("minecraft:overworld_height".to_string(), json!(height));
+        }
+        if !self.tags.is_empty() {
+            components.insert("minecraft:tags".to_string(), json!({ "tags": self.tags }));
+        }
+
+        json!({
+            "format_version": self.format_version.render(),
+            "minecraft:biome": {
+                "description": {
+                    "identifier": self.id.render()
+                },
+                "components": components
+            }
+        })
+        .to_string()
+    }
+}
+
+/// Serializes `biomes_client.json` for all the biomes that have client data.
+pub fn serialize_client_biomes(biomes: &Vec<Biome>) -> String {
+    let mut entries = Map::new();
+
+    for biome in biomes {
+        if let Some(client) = &biome.client {
+            entries.insert(biome.id.value.clone(), client.serialize());
+        }
+    }
+
+    json!({ "biomes": entries }).to_string()
+}
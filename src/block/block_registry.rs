@@ -1,7 +1,10 @@
+use super::culling::{BlockCullingIssue, BlockCullingRules};
 use super::Block;
 use crate::image::Image;
 use crate::vio::{Buildable, Generatable, Identifier};
 use askama::Template;
+use serde_json::{json, Value};
+use std::fmt;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -12,6 +15,8 @@ pub struct BlockRegistry {
     pub block_atlas: Vec<Arc<dyn BlockAtlasEntry>>,
     pub terrain_atlas: Vec<TerrainAtlasEntry>,
     pub textures: Vec<BlockTexture>,
+    pub flipbooks: Vec<FlipbookTexture>,
+    pub culling_rules: Vec<BlockCullingRules>,
 }
 
 #[derive(Template)]
@@ -44,6 +49,8 @@ struct AllBlockAtlasEntryTemplate {
 pub trait BlockAtlasEntry {
     fn serialize(&self) -> String;
     fn id(&self) -> Identifier;
+    /// The terrain texture ids the entry refers to.
+    fn textures(&self) -> Vec<String>;
 }
 
 #[derive(Clone)]
@@ -137,6 +144,19 @@ impl BlockAtlasEntry for PerFaceBlockAtlasEntry {
     fn id(&self) -> Identifier {
         self.id.clone()
     }
+
+    fn textures(&self) -> Vec<String> {
+        let faces = &self.textures;
+
+        vec![
+            faces.up.clone(),
+            faces.down.clone(),
+            faces.north.clone(),
+            faces.south.clone(),
+            faces.east.clone(),
+            faces.west.clone(),
+        ]
+    }
 }
 
 #[derive(Template)]
@@ -205,6 +225,10 @@ impl BlockAtlasEntry for AllBlockAtlasEntry {
     fn id(&self) -> Identifier {
         self.id.clone()
     }
+
+    fn textures(&self) -> Vec<String> {
+        vec![self.textures.clone()]
+    }
 }
 
 pub fn serialize_block_atlas(atlas: &Vec<Arc<dyn BlockAtlasEntry>>) -> String {
@@ -234,6 +258,8 @@ impl BlockRegistry {
             block_atlas: vec![],
             terrain_atlas: vec![],
             textures: vec![],
+            flipbooks: vec![],
+            culling_rules: vec![],
         }
     }
 
@@ -269,6 +295,134 @@ impl BlockRegistry {
         self.block_atlas.push(entry.clone());
     }
 
+    /// Checks that the material instances of the blocks and the atlas entries
+    /// refer to terrain textures that are registered, and that every
+    /// registered terrain texture is used. Ids without a namespace that
+    /// aren't registered are taken to be vanilla textures.
+    pub fn validate_textures(&self) -> Vec<BlockTextureIssue> {
+        let mut issues = vec![];
+
+        let mut referenced: Vec<(String, String)> = vec![];
+        for block in &self.blocks {
+            let owner = format!("block \"{}\"", block.type_id().render());
+            for texture in block.material_instance_textures() {
+                referenced.push((owner.clone(), texture));
+            }
+        }
+        for entry in &self.block_atlas {
+            let owner = format!("blocks.json entry \"{}\"", entry.id().render());
+            for texture in entry.textures() {
+                referenced.push((owner.clone(), texture));
+            }
+        }
+
+        let mut checked = referenced.clone();
+        for flipbook in &self.flipbooks {
+            checked.push((
+                "flipbook texture".to_string(),
+                flipbook.atlas_tile().render(),
+            ));
+        }
+
+        for (owner, texture) in &checked {
+            let registered = self.terrain_atlas.iter().any(|entry| &entry.id == texture);
+            if !registered && texture.contains(':') {
+                issues.push(BlockTextureIssue::DanglingTexture {
+                    owner: owner.clone(),
+                    texture: texture.clone(),
+                });
+            }
+        }
+
+        for entry in &self.terrain_atlas {
+            if !referenced.iter().any(|(_, texture)| texture == &entry.id) {
+                issues.push(BlockTextureIssue::UnusedTexture {
+                    texture: entry.id.clone(),
+                });
+            }
+        }
+
+        let duplicates = [
+            (
+                "block",
+                self.blocks
+                    .iter()
+                    .map(|block| block.type_id().render())
+                    .collect::<Vec<String>>(),
+            ),
+            (
+                "blocks.json entry",
+                self.block_atlas
+                    .iter()
+                    .map(|entry| entry.id().render())
+                    .collect(),
+            ),
+            (
+                "terrain texture",
+                self.terrain_atlas
+                    .iter()
+                    .map(|entry| entry.id.clone())
+                    .collect(),
+            ),
+            (
+                "texture file",
+                self.textures
+                    .iter()
+                    .map(|texture| texture.texture_name())
+                    .collect(),
+            ),
+        ];
+        for (kind, ids) in duplicates {
+            let mut seen: Vec<&String> = vec![];
+            let mut reported: Vec<&String> = vec![];
+            for id in &ids {
+                if seen.contains(&id) && !reported.contains(&id) {
+                    issues.push(BlockTextureIssue::DuplicateId {
+                        kind: kind.to_string(),
+                        id: id.clone(),
+                    });
+                    reported.push(id);
+                }
+                seen.push(id);
+            }
+        }
+
+        issues
+    }
+
+    /// Checks that the culling rules the block geometries refer to are
+    /// registered and have unique ids. Rules in the `minecraft` namespace are
+    /// taken to be vanilla.
+    pub fn validate_culling_rules(&self) -> Vec<BlockCullingIssue> {
+        let mut issues = vec![];
+
+        for block in &self.blocks {
+            for rules in block.geometry_culling_rules() {
+                let registered = self
+                    .culling_rules
+                    .iter()
+                    .any(|culling| culling.id.render() == rules);
+                if !registered && !rules.starts_with("minecraft:") {
+                    issues.push(BlockCullingIssue::UnregisteredRules {
+                        block: block.type_id().render(),
+                        rules,
+                    });
+                }
+            }
+        }
+
+        let mut seen: Vec<String> = vec![];
+        for culling in &self.culling_rules {
+            let id = culling.id.render();
+            if seen.contains(&id) {
+                issues.push(BlockCullingIssue::DuplicateId { id: id.clone() });
+            }
+            seen.push(id);
+        }
+
+        issues
+    }
+
     pub fn unified_add_texture(&mut self, texture: BlockTexture) {
         self.add_texture(texture.clone());
         self.add_terrain_atlas_entry(TerrainAtlasEntry {
@@ -278,6 +432,37 @@ impl BlockRegistry {
     }
 }
 
+/// A problem found by `BlockRegistry::validate_textures`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlockTextureIssue {
+    /// A material instance or atlas entry refers to a texture that isn't in
+    /// the terrain atlas.
+    DanglingTexture { owner: String, texture: String },
+    /// A terrain texture nothing refers to.
+    UnusedTexture { texture: String },
+    /// Two blocks, atlas entries, terrain textures or texture files share an
+    /// id.
+    DuplicateId { kind: String, id: String },
+}
+
+impl fmt::Display for BlockTextureIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DanglingTexture { owner, texture } => write!(
+                f,
+                "The {} refers to the unregistered texture \"{}\"",
+                owner, texture
+            ),
+            Self::UnusedTexture { texture } => {
+                write!(f, "The texture \"{}\" isn't used by any block", texture)
+            }
+            Self::DuplicateId { kind, id } => {
+                write!(f, "More than one {} has the id \"{}\"", kind, id)
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct BlockTexture {
     src: Image,
@@ -307,6 +492,107 @@ impl BlockTexture {
     }
 }
 
+/// The frames of a flipbook texture.
+#[derive(Clone)]
+pub enum FlipbookSource {
+    /// The frames stacked from top to bottom in one image.
+    Strip(Image),
+    /// Separate frames, stitched into a strip when the texture is created.
+    Frames(Vec<Image>),
+}
+
+/// An animated block texture, written to `flipbook_textures.json`. The strip
+/// is a regular `BlockTexture`, so material instances refer to it by its id.
+#[derive(Clone)]
+pub struct FlipbookTexture {
+    pub texture: BlockTexture,
+    pub ticks_per_frame: u32,
+    /// The order the frames are shown in, by index. All frames top to bottom
+    /// if `None`.
+    pub frames: Option<Vec<u32>>,
+    pub blend_frames: Option<bool>,
+    pub replicate: Option<u32>,
+}
+
+impl FlipbookTexture {
+    /// Returns `None` if `Frames` is empty or not all squares of the same
+    /// size, or if the height of a `Strip` isn't a multiple of its width.
+    pub fn new(
+        atlas_tile: Identifier,
+        file_name: impl Into<String>,
+        source: FlipbookSource,
+        ticks_per_frame: u32,
+    ) -> Option<Self> {
+        let strip = match source {
+            FlipbookSource::Strip(strip) => {
+                if strip.width() == 0 || strip.height() % strip.width() != 0 {
+                    return None;
+                }
+
+                strip
+            }
+            FlipbookSource::Frames(frames) => Image::vertical_strip(&frames)?,
+        };
+
+        Some(Self {
+            texture: BlockTexture::new(strip, atlas_tile, file_name),
+            ticks_per_frame,
+            frames: None,
+            blend_frames: None,
+            replicate: None,
+        })
+    }
+
+    pub fn using_frames(self, frames: Vec<u32>) -> Self {
+        Self {
+            frames: Some(frames),
+            ..self
+        }
+    }
+
+    pub fn using_blend_frames(self, blend_frames: bool) -> Self {
+        Self {
+            blend_frames: Some(blend_frames),
+            ..self
+        }
+    }
+
+    pub fn using_replicate(self, replicate: u32) -> Self {
+        Self {
+            replicate: Some(replicate),
+            ..self
+        }
+    }
+
+    /// The terrain texture id the animation plays on.
+    pub fn atlas_tile(&self) -> Identifier {
+        self.texture.id()
+    }
+
+    pub fn value(&self) -> Value {
+        let mut value = json!({
+            "flipbook_texture": format!("textures/blocks/{}", self.texture.texture_name()),
+            "atlas_tile": self.atlas_tile().render(),
+            "ticks_per_frame": self.ticks_per_frame,
+        });
+        if let Some(frames) = &self.frames {
+            value["frames"] = json!(frames);
+        }
+        if let Some(blend_frames) = self.blend_frames {
+            value["blend_frames"] = json!(blend_frames);
+        }
+        if let Some(replicate) = self.replicate {
+            value["replicate"] = json!(replicate);
+        }
+
+        value
+    }
+}
+
+pub fn serialize_flipbook_textures(flipbooks: &[FlipbookTexture]) -> Value {
+    Value::Array(flipbooks.iter().map(|flipbook| flipbook.value()).collect())
+}
+
 impl Buildable for AllBlockAtlasEntry {}
 impl Buildable for PerFaceBlockAtlasEntry {}
 
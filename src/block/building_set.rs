@@ -0,0 +1,857 @@
+use crate::asset::AssetSource;
+use crate::block::block_registry::{AllBlockAtlasEntry, BlockTexture};
+use crate::block::component::{
+    BlockCollisionBoxComponent, BlockComponent, BlockCustomComponentsComponent,
+    BlockDestructibleByExplosionComponent, BlockDestructibleByMiningComponent,
+    BlockGeometryComponent, BlockLightDampeningComponent, BlockLootComponent,
+    BlockMaterialInstancesComponent, BlockSelectionBoxComponent, BlockTagComponent,
+    BlockTransformationComponent,
+};
+use crate::block::condition::BlockCondition;
+use crate::block::permutation::BlockPermutation;
+use crate::block::state::{BlockState, BoolBlockState};
+use crate::block::traits::{
+    ConnectionState, ConnectionTrait, PlacementDirectionState, PlacementDirectionTrait,
+    PlacementPositionState, PlacementPositionTrait,
+};
+use crate::block::utils::{BlockFace, BlockRenderMethod, MaterialInstance};
+use crate::block::Block;
+use crate::image::Image;
+use crate::item::utils::{capitalized, MenuCategory, MenuCategoryName};
+use crate::localization::Localization;
+use crate::pack::{Pack, PackPart};
+use crate::recipe::{RecipeIO, ShapedRecipe};
+use crate::vio::{Buildable, Identifier, MolangStatement, Vec3};
+use crate::vio_vanilla::script_additions::BuildingSetComponents;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const FULL_BLOCK_GEOMETRY: &str = "minecraft:geometry.full_block";
+
+/// The shapes a building set can generate besides its base block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockPreset {
+    Slab,
+    Stairs,
+    Wall,
+    Fence,
+    Door,
+    Trapdoor,
+    Log,
+    Pillar,
+}
+
+impl BlockPreset {
+    pub fn all() -> Vec<BlockPreset> {
+        vec![
+            BlockPreset::Slab,
+            BlockPreset::Stairs,
+            BlockPreset::Wall,
+            BlockPreset::Fence,
+            BlockPreset::Door,
+            BlockPreset::Trapdoor,
+            BlockPreset::Log,
+            BlockPreset::Pillar,
+        ]
+    }
+
+    pub fn str_kind(&self) -> &'static str {
+        match self {
+            BlockPreset::Slab => "slab",
+            BlockPreset::Stairs => "stairs",
+            BlockPreset::Wall => "wall",
+            BlockPreset::Fence => "fence",
+            BlockPreset::Door => "door",
+            BlockPreset::Trapdoor => "trapdoor",
+            BlockPreset::Log => "log",
+            BlockPreset::Pillar => "pillar",
+        }
+    }
+
+    /// The crafting pattern from the base block (`X`) and sticks (`S`), with
+    /// the number of blocks it makes. Logs aren't craftable.
+    fn pattern(&self) -> Option<(Vec<&'static str>, i32)> {
+        match self {
+            BlockPreset::Slab => Some((vec!["XXX"], 6)),
+            BlockPreset::Stairs => Some((vec!["X  ", "XX ", "XXX"], 4)),
+            BlockPreset::Wall => Some((vec!["XXX", "XXX"], 6)),
+            BlockPreset::Fence => Some((vec!["XSX", "XSX"], 3)),
+            BlockPreset::Door => Some((vec!["XX", "XX", "XX"], 3)),
+            BlockPreset::Trapdoor => Some((vec!["XXX", "XXX"], 2)),
+            BlockPreset::Log => None,
+            BlockPreset::Pillar => Some((vec!["X", "X"], 2)),
+        }
+    }
+
+    /// The vanilla creative inventory group of the shape.
+    fn group(&self) -> Option<&'static str> {
+        match self {
+            BlockPreset::Slab => Some("itemGroup.name.slab"),
+            BlockPreset::Stairs => Some("itemGroup.name.stairs"),
+            BlockPreset::Wall => Some("itemGroup.name.walls"),
+            BlockPreset::Fence => Some("itemGroup.name.fence"),
+            BlockPreset::Door => Some("itemGroup.name.door"),
+            BlockPreset::Trapdoor => Some("itemGroup.name.trapdoor"),
+            BlockPreset::Log => Some("itemGroup.name.log"),
+            BlockPreset::Pillar => None,
+        }
+    }
+
+    /// Whether the shape needs the script components of the set, see
+    /// `BuildingSetComponents`.
+    pub fn is_scripted(&self) -> bool {
+        matches!(
+            self,
+            BlockPreset::Slab | BlockPreset::Door | BlockPreset::Trapdoor
+        )
+    }
+}
+
+/// Generates a base block of a material and the given shapes of it: blocks
+/// with geometry, material instances, states and permutations, crafting
+/// recipes, loot for double slabs and names.
+///
+/// Slabs stack, doors and trapdoors open and doors place their upper half
+/// through custom components, so a set with those shapes needs a pack with
+/// scripts. Collision and selection are a single box per block, so walls and
+/// fences keep the full block.
+///
+/// ```ignore
+/// BuildingSet::new("violin", "marble", "Marble", Image::new("./marble.png"), BlockPreset::all())
+///     .register(&mut pack, &mut localization);
+/// ```
+#[derive(Clone)]
+pub struct BuildingSet {
+    pub namespace: String,
+    pub material: String,
+    pub display_name: String,
+    pub texture: Image,
+    /// Texture of the top and bottom of logs and pillars, defaults to
+    /// `texture`.
+    pub end_texture: Option<Image>,
+    pub presets: Vec<BlockPreset>,
+    pub seconds_to_destroy: f64,
+    pub explosion_resistance: f64,
+    pub sound: String,
+    /// Block tags, e.g. `stone` for pickaxes to mine the blocks faster.
+    pub tags: Vec<String>,
+}
+
+impl BuildingSet {
+    pub fn new(
+        namespace: impl Into<String>,
+        material: impl Into<String>,
+        display_name: impl Into<String>,
+        texture: Image,
+        presets: Vec<BlockPreset>,
+    ) -> Self {
+        Self {
+            namespace: namespace.into(),
+            material: material.into(),
+            display_name: display_name.into(),
+            texture,
+            end_texture: None,
+            presets,
+            seconds_to_destroy: 1.5,
+            explosion_resistance: 6.0,
+            sound: "stone".to_string(),
+            tags: vec![
+                "stone".to_string(),
+                "minecraft:is_pickaxe_item_destructible".to_string(),
+            ],
+        }
+    }
+
+    pub fn using_end_texture(self, end_texture: Image) -> Self {
+        Self {
+            end_texture: Some(end_texture),
+            ..self
+        }
+    }
+
+    pub fn using_seconds_to_destroy(self, seconds_to_destroy: f64) -> Self {
+        Self {
+            seconds_to_destroy,
+            ..self
+        }
+    }
+
+    pub fn using_explosion_resistance(self, explosion_resistance: f64) -> Self {
+        Self {
+            explosion_resistance,
+            ..self
+        }
+    }
+
+    pub fn using_sound(self, sound: impl Into<String>) -> Self {
+        Self {
+            sound: sound.into(),
+            ..self
+        }
+    }
+
+    pub fn using_tags(self, tags: Vec<impl Into<String>>) -> Self {
+        Self {
+            tags: tags.into_iter().map(|tag| tag.into()).collect(),
+            ..self
+        }
+    }
+
+    /// The id of the full block the shapes are crafted from, e.g. `violin:marble`.
+    pub fn base_id(&self) -> Identifier {
+        Identifier::new(&self.namespace, &self.material)
+    }
+
+    pub fn block_id(&self, preset: BlockPreset) -> Identifier {
+        Identifier::new(
+            &self.namespace,
+            format!("{}_{}", self.material, preset.str_kind()),
+        )
+    }
+
+    fn side_texture(&self) -> BlockTexture {
+        BlockTexture::new(
+            self.texture.clone(),
+            self.base_id(),
+            format!("{}_{}", self.namespace, self.material),
+        )
+    }
+
+    fn end_texture(&self) -> BlockTexture {
+        match &self.end_texture {
+            Some(end_texture) => BlockTexture::new(
+                end_texture.clone(),
+                Identifier::new(&self.namespace, format!("{}_end", self.material)),
+                format!("{}_{}_end", self.namespace, self.material),
+            ),
+            None => self.side_texture(),
+        }
+    }
+
+    fn state(&self, name: &str) -> Arc<dyn BlockState> {
+        BoolBlockState {
+            id: Identifier::new(&self.namespace, name),
+        }
+        .build()
+    }
+
+    fn geometry_id(&self, preset: BlockPreset) -> String {
+        format!(
+            "geometry.{}.{}_{}",
+            self.namespace,
+            self.material,
+            preset.str_kind()
+        )
+    }
+
+    fn loot_table_path(&self, preset: BlockPreset) -> String {
+        format!(
+            "loot_tables/blocks/{}_{}_{}_double.json",
+            self.namespace,
+            self.material,
+            preset.str_kind()
+        )
+    }
+
+    fn material_instances(&self, end: &BlockTexture) -> BlockMaterialInstancesComponent {
+        let side = self.side_texture();
+        let instance = |texture| MaterialInstance::from_texture(texture, BlockRenderMethod::Opaque);
+
+        BlockMaterialInstancesComponent::new(HashMap::from([
+            (BlockFace::Up, instance(end)),
+            (BlockFace::Down, instance(end)),
+            (BlockFace::North, instance(&side)),
+            (BlockFace::South, instance(&side)),
+            (BlockFace::East, instance(&side)),
+            (BlockFace::West, instance(&side)),
+        ]))
+    }
+
+    /// Components every block of the set has. `end` is the texture of the top
+    /// and bottom.
+    fn components(
+        &self,
+        geometry: BlockGeometryComponent,
+        end: &BlockTexture,
+    ) -> Vec<Arc<dyn BlockComponent>> {
+        let mut components: Vec<Arc<dyn BlockComponent>> = vec![
+            geometry.build(),
+            self.material_instances(end).build(),
+            BlockDestructibleByMiningComponent::new(self.seconds_to_destroy).build(),
+            BlockDestructibleByExplosionComponent::new(self.explosion_resistance).build(),
+        ];
+        for tag in &self.tags {
+            components.push(BlockTagComponent::new(tag.as_str()).build());
+        }
+
+        components
+    }
+
+    /// Components of a shape smaller than a full block.
+    fn partial_components(
+        &self,
+        preset: BlockPreset,
+        bone_visibility: HashMap<String, MolangStatement>,
+    ) -> Vec<Arc<dyn BlockComponent>> {
+        let mut components = self.components(
+            BlockGeometryComponent::new(self.geometry_id(preset), bone_visibility),
+            &self.side_texture(),
+        );
+        components.push(BlockLightDampeningComponent::new(0).build());
+        if preset.is_scripted() {
+            components
+                .push(BlockCustomComponentsComponent::new(vec![self.block_id(preset)]).build());
+        }
+
+        components
+    }
+
+    fn menu_category(&self, preset: Option<BlockPreset>) -> MenuCategory {
+        let category = MenuCategory::new(MenuCategoryName::Construction);
+
+        match preset.and_then(|preset| preset.group()) {
+            Some(group) => category.using_group(group),
+            None => category,
+        }
+    }
+
+    /// The full block the shapes are made from.
+    pub fn base_block(&self) -> Block {
+        Block::new(self.base_id())
+            .using_components(self.components(
+                BlockGeometryComponent::new(FULL_BLOCK_GEOMETRY, HashMap::new()),
+                &self.side_texture(),
+            ))
+            .using_textures(vec![self.side_texture()])
+            .using_menu_category(self.menu_category(None))
+    }
+
+    pub fn block(&self, preset: BlockPreset) -> Block {
+        let mut block = match preset {
+            BlockPreset::Slab => self.slab(),
+            BlockPreset::Stairs => self.stairs(),
+            BlockPreset::Wall => self.connected(BlockPreset::Wall),
+            BlockPreset::Fence => self.connected(BlockPreset::Fence),
+            BlockPreset::Door => self.door(),
+            BlockPreset::Trapdoor => self.trapdoor(),
+            BlockPreset::Log | BlockPreset::Pillar => self.log(preset),
+        };
+
+        let textures = match preset {
+            BlockPreset::Log | BlockPreset::Pillar => self.textures(),
+            _ => vec![self.side_texture()],
+        };
+
+        block
+            .using_textures(textures)
+            .using_menu_category(self.menu_category(Some(preset)))
+    }
+
+    fn slab(&self) -> Block {
+        let double = self.state("double");
+        let half = PlacementPositionState::VerticalHalf.state();
+
+        Block::new(self.block_id(BlockPreset::Slab))
+            .using_components(
+                [
+                    self.partial_components(BlockPreset::Slab, vertical_half_visibility()),
+                    half_boxes(0.0),
+                ]
+                .concat(),
+            )
+            .using_states(vec![double.clone()])
+            .using_traits(vec![PlacementPositionTrait::new(vec![
+                PlacementPositionState::VerticalHalf,
+            ])
+            .build()])
+            .using_permutations(vec![
+                BlockPermutation::new_typed(
+                    BlockCondition::and(vec![
+                        BlockCondition::equals(half.as_ref(), "top"),
+                        BlockCondition::equals(double.as_ref(), false),
+                    ]),
+                    half_boxes(8.0),
+                ),
+                BlockPermutation::new_typed(
+                    BlockCondition::equals(double.as_ref(), true),
+                    vec![
+                        BlockGeometryComponent::new(FULL_BLOCK_GEOMETRY, HashMap::new()).build(),
+                        BlockCollisionBoxComponent::full().build(),
+                        BlockSelectionBoxComponent::full().build(),
+                        BlockLightDampeningComponent::new(15).build(),
+                        BlockLootComponent::new(self.loot_table_path(BlockPreset::Slab)).build(),
+                    ],
+                ),
+            ])
+    }
+
+    fn stairs(&self) -> Block {
+        Block::new(self.block_id(BlockPreset::Stairs))
+            .using_components(
+                self.partial_components(BlockPreset::Stairs, vertical_half_visibility()),
+            )
+            .using_traits(vec![
+                PlacementDirectionTrait::new(vec![PlacementDirectionState::CardinalDirection])
+                    .build(),
+                PlacementPositionTrait::new(vec![PlacementPositionState::VerticalHalf]).build(),
+            ])
+            .using_permutations(cardinal_permutations())
+    }
+
+    /// A wall or a fence, whose arms show towards the connected neighbours.
+    fn connected(&self, preset: BlockPreset) -> Block {
+        let bone_visibility = ["north", "east", "south", "west"]
+            .iter()
+            .map(|direction| {
+                (
+                    direction.to_string(),
+                    MolangStatement::new(format!(
+                        "q.block_state('minecraft:connection_{}')",
+                        direction
+                    )),
+                )
+            })
+            .collect();
+
+        Block::new(self.block_id(preset))
+            .using_components(self.partial_components(preset, bone_visibility))
+            .using_traits(vec![ConnectionTrait::new(vec![
+                ConnectionState::CardinalConnections,
+            ])
+            .build()])
+    }
+
+    fn door(&self) -> Block {
+        let open = self.state("open");
+        let upper = self.state("upper_block");
+
+        let mut permutations = cardinal_permutations();
+        permutations.extend(open_permutations(
+            open.as_ref(),
+            box_components(vec3(-8.0, 0.0, -8.0), vec3(16.0, 16.0, 3.0)),
+            box_components(vec3(-8.0, 0.0, -8.0), vec3(3.0, 16.0, 16.0)),
+        ));
+        permutations.push(BlockPermutation::new_typed(
+            BlockCondition::equals(upper.as_ref(), true),
+            vec![BlockLootComponent::new("loot_tables/empty.json").build()],
+        ));
+
+        Block::new(self.block_id(BlockPreset::Door))
+            .using_components(
+                self.partial_components(BlockPreset::Door, open_visibility(&self.namespace)),
+            )
+            .using_states(vec![open, upper])
+            .using_traits(vec![PlacementDirectionTrait::new(vec![
+                PlacementDirectionState::CardinalDirection,
+            ])
+            .build()])
+            .using_permutations(permutations)
+    }
+
+    fn trapdoor(&self) -> Block {
+        let open = self.state("open");
+        let half = PlacementPositionState::VerticalHalf.state();
+
+        let closed = |half: &str| {
+            MolangStatement::new(format!(
+                "!q.block_state('{}:open') && q.block_state('minecraft:vertical_half') == '{}'",
+                self.namespace, half
+            ))
+        };
+        let bone_visibility = HashMap::from([
+            (
+                "open".to_string(),
+                MolangStatement::new(format!("q.block_state('{}:open')", self.namespace)),
+            ),
+            ("bottom".to_string(), closed("bottom")),
+            ("top".to_string(), closed("top")),
+        ]);
+
+        let mut permutations = cardinal_permutations();
+        permutations.extend(open_permutations(
+            open.as_ref(),
+            box_components(vec3(-8.0, 0.0, -8.0), vec3(16.0, 3.0, 16.0)),
+            box_components(vec3(-8.0, 0.0, -8.0), vec3(16.0, 16.0, 3.0)),
+        ));
+        permutations.push(BlockPermutation::new_typed(
+            BlockCondition::and(vec![
+                BlockCondition::equals(half.as_ref(), "top"),
+                BlockCondition::equals(open.as_ref(), false),
+            ]),
+            box_components(vec3(-8.0, 13.0, -8.0), vec3(16.0, 3.0, 16.0)),
+        ));
+
+        Block::new(self.block_id(BlockPreset::Trapdoor))
+            .using_components(self.partial_components(BlockPreset::Trapdoor, bone_visibility))
+            .using_states(vec![open])
+            .using_traits(vec![
+                PlacementDirectionTrait::new(vec![PlacementDirectionState::CardinalDirection])
+                    .build(),
+                PlacementPositionTrait::new(vec![PlacementPositionState::VerticalHalf]).build(),
+            ])
+            .using_permutations(permutations)
+    }
+
+    /// A full block rotated towards the face it was placed against.
+    fn log(&self, preset: BlockPreset) -> Block {
+        let face = PlacementPositionState::BlockFace.state();
+        let rotations = [
+            (vec!["north", "south"], vec3(90.0, 0.0, 0.0)),
+            (vec!["east", "west"], vec3(0.0, 0.0, 90.0)),
+        ];
+
+        Block::new(self.block_id(preset))
+            .using_components(self.components(
+                BlockGeometryComponent::new(FULL_BLOCK_GEOMETRY, HashMap::new()),
+                &self.end_texture(),
+            ))
+            .using_traits(vec![PlacementPositionTrait::new(vec![
+                PlacementPositionState::BlockFace,
+            ])
+            .build()])
+            .using_permutations(
+                rotations
+                    .into_iter()
+                    .map(|(faces, rotation)| {
+                        BlockPermutation::new_typed(
+                            BlockCondition::in_set(face.as_ref(), faces),
+                            vec![transformation(rotation).build()],
+                        )
+                    })
+                    .collect(),
+            )
+    }
+
+    /// The base block followed by the shapes, in `presets` order.
+    pub fn blocks(&self) -> Vec<Block> {
+        let mut blocks = vec![self.base_block()];
+        blocks.extend(self.presets.iter().map(|preset| self.block(*preset)));
+
+        blocks
+    }
+
+    /// The textures of the set, which the blocks declare inline.
+    pub fn textures(&self) -> Vec<BlockTexture> {
+        match self.end_texture {
+            Some(_) => vec![self.side_texture(), self.end_texture()],
+            None => vec![self.side_texture()],
+        }
+    }
+
+    /// `blocks.json` entries giving the blocks the set's sound.
+    pub fn atlas_entries(&self) -> Vec<AllBlockAtlasEntry> {
+        self.blocks()
+            .iter()
+            .map(|block| {
+                AllBlockAtlasEntry::new(
+                    block.type_id(),
+                    self.side_texture().id().render(),
+                    &self.sound,
+                )
+            })
+            .collect()
+    }
+
+    /// The geometry files of the shapes, as RP relative path and contents.
+    pub fn geometries(&self) -> Vec<(String, Value)> {
+        self.presets
+            .iter()
+            .filter_map(|preset| {
+                let bones = match preset {
+                    BlockPreset::Slab => vec![
+                        ("bottom", vec![([-8.0, 0.0, -8.0], [16.0, 8.0, 16.0])]),
+                        ("top", vec![([-8.0, 8.0, -8.0], [16.0, 8.0, 16.0])]),
+                    ],
+                    BlockPreset::Stairs => vec![
+                        (
+                            "bottom",
+                            vec![
+                                ([-8.0, 0.0, -8.0], [16.0, 8.0, 16.0]),
+                                ([-8.0, 8.0, -8.0], [16.0, 8.0, 8.0]),
+                            ],
+                        ),
+                        (
+                            "top",
+                            vec![
+                                ([-8.0, 8.0, -8.0], [16.0, 8.0, 16.0]),
+                                ([-8.0, 0.0, -8.0], [16.0, 8.0, 8.0]),
+                            ],
+                        ),
+                    ],
+                    BlockPreset::Wall => vec![
+                        ("post", vec![([-4.0, 0.0, -4.0], [8.0, 16.0, 8.0])]),
+                        ("north", vec![([-3.0, 0.0, -8.0], [6.0, 14.0, 4.0])]),
+                        ("south", vec![([-3.0, 0.0, 4.0], [6.0, 14.0, 4.0])]),
+                        ("east", vec![([4.0, 0.0, -3.0], [4.0, 14.0, 6.0])]),
+                        ("west", vec![([-8.0, 0.0, -3.0], [4.0, 14.0, 6.0])]),
+                    ],
+                    BlockPreset::Fence => vec![
+                        ("post", vec![([-2.0, 0.0, -2.0], [4.0, 16.0, 4.0])]),
+                        (
+                            "north",
+                            vec![
+                                ([-1.0, 6.0, -8.0], [2.0, 3.0, 6.0]),
+                                ([-1.0, 12.0, -8.0], [2.0, 3.0, 6.0]),
+                            ],
+                        ),
+                        (
+                            "south",
+                            vec![
+                                ([-1.0, 6.0, 2.0], [2.0, 3.0, 6.0]),
+                                ([-1.0, 12.0, 2.0], [2.0, 3.0, 6.0]),
+                            ],
+                        ),
+                        (
+                            "east",
+                            vec![
+                                ([2.0, 6.0, -1.0], [6.0, 3.0, 2.0]),
+                                ([2.0, 12.0, -1.0], [6.0, 3.0, 2.0]),
+                            ],
+                        ),
+                        (
+                            "west",
+                            vec![
+                                ([-8.0, 6.0, -1.0], [6.0, 3.0, 2.0]),
+                                ([-8.0, 12.0, -1.0], [6.0, 3.0, 2.0]),
+                            ],
+                        ),
+                    ],
+                    BlockPreset::Door => vec![
+                        ("closed", vec![([-8.0, 0.0, -8.0], [16.0, 16.0, 3.0])]),
+                        ("open", vec![([-8.0, 0.0, -8.0], [3.0, 16.0, 16.0])]),
+                    ],
+                    BlockPreset::Trapdoor => vec![
+                        ("bottom", vec![([-8.0, 0.0, -8.0], [16.0, 3.0, 16.0])]),
+                        ("top", vec![([-8.0, 13.0, -8.0], [16.0, 3.0, 16.0])]),
+                        ("open", vec![([-8.0, 0.0, -8.0], [16.0, 16.0, 3.0])]),
+                    ],
+                    BlockPreset::Log | BlockPreset::Pillar => return None,
+                };
+
+                Some((
+                    format!(
+                        "models/blocks/{}_{}_{}.geo.json",
+                        self.namespace,
+                        self.material,
+                        preset.str_kind()
+                    ),
+                    geometry(&self.geometry_id(*preset), bones),
+                ))
+            })
+            .collect()
+    }
+
+    /// The BP loot tables of the set, as BP relative path and contents.
+    pub fn loot_tables(&self) -> Vec<(String, Value)> {
+        if !self.presets.contains(&BlockPreset::Slab) {
+            return vec![];
+        }
+
+        vec![(
+            self.loot_table_path(BlockPreset::Slab),
+            json!({
+                "pools": [{
+                    "rolls": 1,
+                    "entries": [{
+                        "type": "item",
+                        "name": self.block_id(BlockPreset::Slab).render(),
+                        "functions": [{ "function": "set_count", "count": 2 }]
+                    }]
+                }]
+            }),
+        )]
+    }
+
+    pub fn recipes(&self) -> Vec<ShapedRecipe> {
+        self.presets
+            .iter()
+            .filter_map(|preset| {
+                let (pattern, count) = preset.pattern()?;
+                let id = self.block_id(*preset);
+
+                let mut ingredients = vec![RecipeIO::new_typed(self.base_id()).using_key('X')];
+                if pattern.iter().any(|row| row.contains('S')) {
+                    ingredients.push(
+                        RecipeIO::new_typed(Identifier::new("minecraft", "stick")).using_key('S'),
+                    );
+                }
+
+                Some(
+                    ShapedRecipe::new(
+                        Identifier::new(&self.namespace, format!("{}_recipe", id.value)),
+                        RecipeIO::new_typed(id).using_count(count),
+                    )
+                    .using_ingredients(ingredients)
+                    .using_pattern(pattern)
+                    .using_tags(vec!["crafting_table"]),
+                )
+            })
+            .collect()
+    }
+
+    pub fn add_names(&self, localization: &mut Localization) {
+        localization.add_block_name(self.base_id(), &self.display_name);
+        for preset in &self.presets {
+            localization.add_block_name(
+                self.block_id(*preset),
+                format!("{} {}", self.display_name, capitalized(preset.str_kind())),
+            );
+        }
+    }
+
+    /// Registers the blocks, textures, geometry, loot tables, recipes and
+    /// script components of the set in the pack and adds the block names to
+    /// the given localization.
+    pub fn register(&self, pack: &mut Pack, localization: &mut Localization) {
+        for entry in self.atlas_entries() {
+            pack.register_block_atlas_entry(entry.build());
+        }
+        for block in self.blocks() {
+            pack.register_block(block);
+        }
+        for (path, geometry) in self.geometries() {
+            pack.register_asset(PackPart::RP, path, AssetSource::Json(geometry));
+        }
+        for (path, loot_table) in self.loot_tables() {
+            pack.register_asset(PackPart::BP, path, AssetSource::Json(loot_table));
+        }
+        for recipe in self.recipes() {
+            pack.register_recipe(recipe.build());
+        }
+        if self.presets.iter().any(|preset| preset.is_scripted()) {
+            pack.add_script_addition(BuildingSetComponents::new(self.clone()).build());
+        }
+
+        self.add_names(localization);
+    }
+}
+
+fn vec3(x: f64, y: f64, z: f64) -> Vec3 {
+    Vec3 { x, y, z }
+}
+
+fn transformation(rotation: Vec3) -> BlockTransformationComponent {
+    let zero = vec3(0.0, 0.0, 0.0);
+
+    BlockTransformationComponent::new(zero, rotation, vec3(1.0, 1.0, 1.0), zero, zero)
+}
+
+fn box_components(origin: Vec3, size: Vec3) -> Vec<Arc<dyn BlockComponent>> {
+    vec![
+        BlockCollisionBoxComponent::new(origin, size).build(),
+        BlockSelectionBoxComponent::new(origin, size).build(),
+    ]
+}
+
+/// The boxes of a slab starting at the given height.
+fn half_boxes(y: f64) -> Vec<Arc<dyn BlockComponent>> {
+    box_components(vec3(-8.0, y, -8.0), vec3(16.0, 8.0, 16.0))
+}
+
+/// Shows the `bottom` or `top` bone depending on `minecraft:vertical_half`.
+fn vertical_half_visibility() -> HashMap<String, MolangStatement> {
+    ["bottom", "top"]
+        .iter()
+        .map(|half| {
+            (
+                half.to_string(),
+                MolangStatement::new(format!(
+                    "q.block_state('minecraft:vertical_half') == '{}'",
+                    half
+                )),
+            )
+        })
+        .collect()
+}
+
+/// Shows the `open` or `closed` bone depending on the `open` state.
+fn open_visibility(namespace: &str) -> HashMap<String, MolangStatement> {
+    HashMap::from([
+        (
+            "open".to_string(),
+            MolangStatement::new(format!("q.block_state('{}:open')", namespace)),
+        ),
+        (
+            "closed".to_string(),
+            MolangStatement::new(format!("!q.block_state('{}:open')", namespace)),
+        ),
+    ])
+}
+
+/// Rotates the block towards `minecraft:cardinal_direction`.
+fn cardinal_permutations() -> Vec<BlockPermutation> {
+    let direction = PlacementDirectionState::CardinalDirection.state();
+
+    [
+        ("north", 0.0),
+        ("west", 90.0),
+        ("south", 180.0),
+        ("east", -90.0),
+    ]
+    .into_iter()
+    .map(|(value, rotation)| {
+        BlockPermutation::new_typed(
+            BlockCondition::equals(direction.as_ref(), value),
+            vec![transformation(vec3(0.0, rotation, 0.0)).build()],
+        )
+    })
+    .collect()
+}
+
+fn open_permutations(
+    open: &dyn BlockState,
+    closed_components: Vec<Arc<dyn BlockComponent>>,
+    open_components: Vec<Arc<dyn BlockComponent>>,
+) -> Vec<BlockPermutation> {
+    vec![
+        BlockPermutation::new_typed(BlockCondition::equals(open, false), closed_components),
+        BlockPermutation::new_typed(BlockCondition::equals(open, true), open_components),
+    ]
+}
+
+/// The origin and size of a cube of a geometry, in pixels.
+type Cube = ([f64; 3], [f64; 3]);
+
+/// A geometry file of bones made of cubes. UVs map the texture by position,
+/// so shapes of a 16x16 texture line up with the full block.
+fn geometry(identifier: &str, bones: Vec<(&str, Vec<Cube>)>) -> Value {
+    let bones: Vec<Value> = bones
+        .into_iter()
+        .map(|(name, cubes)| {
+            let cubes: Vec<Value> = cubes
+                .into_iter()
+                .map(|([x, y, z], [width, height, depth])| {
+                    let (u, v, w) = (x + 8.0, 16.0 - (y + height), z + 8.0);
+
+                    json!({
+                        "origin": [x, y, z],
+                        "size": [width, height, depth],
+                        "uv": {
+                            "north": { "uv": [u, v], "uv_size": [width, height] },
+                            "south": { "uv": [u, v], "uv_size": [width, height] },
+                            "east": { "uv": [w, v], "uv_size": [depth, height] },
+                            "west": { "uv": [w, v], "uv_size": [depth, height] },
+                            "up": { "uv": [u, w], "uv_size": [width, depth] },
+                            "down": { "uv": [u, w], "uv_size": [width, depth] }
+                        }
+                    })
+                })
+                .collect();
+
+            json!({ "name": name, "pivot": [0, 0, 0], "cubes": cubes })
+        })
+        .collect();
+
+    json!({
+        "format_version": "1.12.0",
+        "minecraft:geometry": [{
+            "description": {
+                "identifier": identifier,
+                "texture_width": 16,
+                "texture_height": 16
+            },
+            "bones": bones
+        }]
+    })
+}
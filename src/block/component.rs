@@ -1,10 +1,19 @@
-use crate::block::utils::{BlockFace, BlockPlacementCondition, MaterialInstance};
-use crate::vio::{Buildable, Identifier, MolangStatement, RGBColor, Vec3};
+use crate::block::utils::{
+    BlockFace, BlockMovementType, BlockPlacementCondition, BlockStickyType, LiquidDetectionRule,
+    MaterialInstance, ParticleTintMethod, PrecipitationBehavior, RandomOffsetAxis,
+};
+use crate::tag::Tag;
+use crate::vio::{json_equivalent, Buildable, Identifier, MolangStatement, RGBColor, Vec3};
 use block_component_macros::block_component;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 pub trait BlockComponent {
-    fn serialize(&self) -> String;
+    /// The key of the component, e.g. `minecraft:geometry`.
+    fn id(&self) -> String;
+    fn value(&self) -> serde_json::Value;
 }
 
 // * BlockCollisionBoxComponent
@@ -142,6 +151,8 @@ block_component! {
     name = Geometry for "minecraft:geometry";
     identifier has String for "identifier" with "public" "into";
     bone_visibility has HashMap<String, MolangStatement> for "bone_visibility" with "public";
+    culling has Option<String> for "culling" with "public" "skip_none" "using" "setter_only";
+    culling_layer has Option<String> for "culling_layer" with "public" "skip_none" "using" "setter_only";
 }
 
 // * BlockLightDampeningComponent
@@ -253,3 +264,205 @@ block_component! {
     name = EntityFallOn for "minecraft:entity_fall_on";
     min_fall_distance has f64 for "min_fall_distance" with "public";
 }
+
+// * ItemVisual
+
+block_component! {
+    name = ItemVisual for "minecraft:item_visual";
+    geometry has BlockGeometryComponent for "geometry" with "public";
+    material_instances has HashMap<BlockFace, MaterialInstance> for "material_instances" with "public";
+}
+
+// * DestructionParticles
+
+block_component! {
+    name = DestructionParticles for "minecraft:destruction_particles";
+    texture has String for "texture" with "public" "into";
+    tint_method has Option<ParticleTintMethod> for "tint_method" with "public" "skip_none" "using";
+    particle_count has Option<u32> for "particle_count" with "public" "skip_none" "using";
+}
+
+// * Replaceable
+
+block_component! {
+    name = Replaceable for "minecraft:replaceable";
+}
+
+// * LiquidDetection
+
+block_component! {
+    name = LiquidDetection for "minecraft:liquid_detection";
+    detection_rules has Vec<LiquidDetectionRule> for "detection_rules" with "public";
+}
+
+// * RedstoneProducer
+
+block_component! {
+    name = RedstoneProducer for "minecraft:redstone_producer";
+    power has u8 for "power" with "public";
+    connected_faces has Option<Vec<BlockFace>> for "connected_faces" with "public" "skip_none" "using";
+    strongly_powered_face has Option<BlockFace> for "strongly_powered_face" with "public" "skip_none" "using";
+    transform_relative has Option<bool> for "transform_relative" with "public" "skip_none" "using";
+}
+
+// * Movable
+
+block_component! {
+    name = Movable for "minecraft:movable";
+    movement_type has BlockMovementType for "movement_type" with "public";
+    sticky has Option<BlockStickyType> for "sticky" with "public" "skip_none" "using";
+}
+
+// * PrecipitationInteractions
+
+block_component! {
+    name = PrecipitationInteractions for "minecraft:precipitation_interactions";
+    precipitation_behavior has PrecipitationBehavior for "precipitation_behavior" with "public";
+}
+
+// * RandomOffset
+
+block_component! {
+    name = RandomOffset for "minecraft:random_offset";
+    x has Option<RandomOffsetAxis> for "x" with "public" "skip_none" "using";
+    y has Option<RandomOffsetAxis> for "y" with "public" "skip_none" "using";
+    z has Option<RandomOffsetAxis> for "z" with "public" "skip_none" "using";
+}
+
+// * BlockTagComponent
+
+/// A `tag:<name>` component, which tags the block for Molang queries like
+/// `q.any_tag`.
+#[derive(Clone, Debug)]
+pub struct BlockTagComponent {
+    pub tag: Tag,
+}
+
+impl BlockTagComponent {
+    pub fn new(tag: impl Into<Tag>) -> Self {
+        Self { tag: tag.into() }
+    }
+}
+
+impl BlockComponent for BlockTagComponent {
+    fn id(&self) -> String {
+        format!("tag:{}", self.tag.name())
+    }
+
+    fn value(&self) -> Value {
+        json!({})
+    }
+}
+
+impl Buildable for BlockTagComponent {}
+
+// * BlockRawComponent
+
+/// A component Violin has no typed struct for, kept as raw JSON.
+#[derive(Clone, Debug)]
+pub struct BlockRawComponent {
+    pub id: String,
+    pub value: Value,
+}
+
+impl BlockRawComponent {
+    pub fn new(id: impl Into<String>, value: Value) -> Self {
+        Self {
+            id: id.into(),
+            value,
+        }
+    }
+}
+
+impl BlockComponent for BlockRawComponent {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn value(&self) -> Value {
+        self.value.clone()
+    }
+}
+
+impl Buildable for BlockRawComponent {}
+
+/// Reads a component of existing block JSON. Known components are mapped to
+/// their typed struct when it represents the value without loss, everything
+/// else is kept as a `BlockRawComponent`.
+pub fn block_component_from_json(id: &str, value: &Value) -> Arc<dyn BlockComponent> {
+    let typed = match id {
+        "minecraft:collision_box" => typed_block_component::<BlockCollisionBoxComponent>(value)
+            .or_else(|| typed_block_component::<BlockNoCollisionBoxComponent>(value)),
+        "minecraft:crafting_table" => typed_block_component::<BlockCraftingTableComponent>(value),
+        "minecraft:destructible_by_explosion" => {
+            typed_block_component::<BlockDestructibleByExplosionComponent>(value)
+                .or_else(|| typed_block_component::<BlockIndestructibleByExplosionComponent>(value))
+        }
+        "minecraft:destructible_by_mining" => {
+            typed_block_component::<BlockDestructibleByMiningComponent>(value)
+                .or_else(|| typed_block_component::<BlockIndestructibleByMiningComponent>(value))
+        }
+        "minecraft:custom_components" => {
+            typed_block_component::<BlockCustomComponentsComponent>(value)
+        }
+        "minecraft:display_name" => typed_block_component::<BlockDisplayNameComponent>(value),
+        "minecraft:flammable" => typed_block_component::<BlockFlammableComponent>(value),
+        "minecraft:friction" => typed_block_component::<BlockFrictionComponent>(value),
+        "minecraft:geometry" => typed_block_component::<BlockGeometryComponent>(value),
+        "minecraft:light_dampening" => typed_block_component::<BlockLightDampeningComponent>(value),
+        "minecraft:light_emission" => typed_block_component::<BlockLightEmissionComponent>(value),
+        "minecraft:loot" => typed_block_component::<BlockLootComponent>(value),
+        "minecraft:map_color" => typed_block_component::<BlockMapColorComponent>(value),
+        "minecraft:material_instances" => {
+            typed_block_component::<BlockMaterialInstancesComponent>(value)
+        }
+        "minecraft:redstone_conductivity" => {
+            typed_block_component::<BlockRedstoneConductivityComponent>(value)
+        }
+        "minecraft:selection_box" => typed_block_component::<BlockSelectionBoxComponent>(value)
+            .or_else(|| typed_block_component::<BlockNoSelectionBoxComponent>(value)),
+        "minecraft:tick" => typed_block_component::<BlockTickComponent>(value),
+        "minecraft:transformation" => typed_block_component::<BlockTransformationComponent>(value),
+        "minecraft:placement_filter" => {
+            typed_block_component::<BlockPlacementFilterComponent>(value)
+        }
+        "minecraft:entity_fall_on" => typed_block_component::<BlockEntityFallOnComponent>(value),
+        "minecraft:item_visual" => typed_block_component::<BlockItemVisualComponent>(value),
+        "minecraft:destruction_particles" => {
+            typed_block_component::<BlockDestructionParticlesComponent>(value)
+        }
+        "minecraft:replaceable" => typed_block_component::<BlockReplaceableComponent>(value),
+        "minecraft:liquid_detection" => {
+            typed_block_component::<BlockLiquidDetectionComponent>(value)
+        }
+        "minecraft:redstone_producer" => {
+            typed_block_component::<BlockRedstoneProducerComponent>(value)
+        }
+        "minecraft:movable" => typed_block_component::<BlockMovableComponent>(value),
+        "minecraft:precipitation_interactions" => {
+            typed_block_component::<BlockPrecipitationInteractionsComponent>(value)
+        }
+        "minecraft:random_offset" => typed_block_component::<BlockRandomOffsetComponent>(value),
+        _ => match id.strip_prefix("tag:") {
+            Some(tag) if value == &json!({}) => {
+                Some(BlockTagComponent::new(tag).build() as Arc<dyn BlockComponent>)
+            }
+            _ => None,
+        },
+    };
+
+    typed.unwrap_or_else(|| BlockRawComponent::new(id, value.clone()).build())
+}
+
+fn typed_block_component<T>(value: &Value) -> Option<Arc<dyn BlockComponent>>
+where
+    T: BlockComponent + DeserializeOwned + 'static,
+{
+    let component: T = serde_json::from_value(value.clone()).ok()?;
+
+    if json_equivalent(&component.value(), value) {
+        Some(Arc::new(component))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,183 @@
+use crate::block::state::{BlockState, StateValue};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Not;
+
+/// A permutation condition built from the block's declared states, rendered
+/// to Molang with `render`.
+///
+/// ```ignore
+/// let lit = BoolBlockState { id: Identifier::new("violin", "lit") };
+/// let power = RangedBlockState::new(Identifier::new("violin", "power"), 0, 15);
+///
+/// BlockCondition::and(vec![
+///     BlockCondition::equals(&lit, true),
+///     !BlockCondition::in_set(&power, vec![0, 1]),
+/// ]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlockCondition {
+    Equals {
+        state: String,
+        value: StateValue,
+    },
+    InSet {
+        state: String,
+        values: Vec<StateValue>,
+    },
+    And(Vec<BlockCondition>),
+    Or(Vec<BlockCondition>),
+    Not(Box<BlockCondition>),
+}
+
+impl BlockCondition {
+    pub fn equals(state: &dyn BlockState, value: impl Into<StateValue>) -> Self {
+        Self::Equals {
+            state: state.id().render(),
+            value: value.into(),
+        }
+    }
+
+    /// Matches when the state has any of the values.
+    pub fn in_set(state: &dyn BlockState, values: Vec<impl Into<StateValue>>) -> Self {
+        Self::InSet {
+            state: state.id().render(),
+            values: values.into_iter().map(|value| value.into()).collect(),
+        }
+    }
+
+    pub fn and(conditions: Vec<BlockCondition>) -> Self {
+        Self::And(conditions)
+    }
+
+    pub fn or(conditions: Vec<BlockCondition>) -> Self {
+        Self::Or(conditions)
+    }
+
+    /// The condition as Molang, e.g. `q.block_state('violin:lit') == true`.
+    pub fn render(&self) -> String {
+        match self {
+            Self::Equals { state, value } => render_equals(state, value),
+            Self::InSet { state, values } => match values.as_slice() {
+                [] => "false".to_string(),
+                [value] => render_equals(state, value),
+                values => format!(
+                    "({})",
+                    values
+                        .iter()
+                        .map(|value| render_equals(state, value))
+                        .collect::<Vec<String>>()
+                        .join(" || ")
+                ),
+            },
+            Self::And(conditions) => render_joined(conditions, " && "),
+            Self::Or(conditions) => render_joined(conditions, " || "),
+            Self::Not(condition) => format!("!({})", condition.render()),
+        }
+    }
+
+    /// Every state the condition compares against, with the values it uses.
+    pub fn referenced_values(&self) -> Vec<(String, StateValue)> {
+        match self {
+            Self::Equals { state, value } => vec![(state.clone(), value.clone())],
+            Self::InSet { state, values } => values
+                .iter()
+                .map(|value| (state.clone(), value.clone()))
+                .collect(),
+            Self::And(conditions) | Self::Or(conditions) => conditions
+                .iter()
+                .flat_map(|condition| condition.referenced_values())
+                .collect(),
+            Self::Not(condition) => condition.referenced_values(),
+        }
+    }
+
+    /// Whether the condition holds for the given value of each state, keyed
+    /// by state id. A state missing from `values` matches nothing.
+    pub fn matches(&self, values: &HashMap<String, StateValue>) -> bool {
+        match self {
+            Self::Equals { state, value } => values.get(state) == Some(value),
+            Self::InSet { state, values: set } => {
+                values.get(state).is_some_and(|value| set.contains(value))
+            }
+            Self::And(conditions) => conditions.iter().all(|condition| condition.matches(values)),
+            Self::Or(conditions) => conditions.iter().any(|condition| condition.matches(values)),
+            Self::Not(condition) => !condition.matches(values),
+        }
+    }
+}
+
+impl Not for BlockCondition {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::Not(Box::new(self))
+    }
+}
+
+fn render_equals(state: &str, value: &StateValue) -> String {
+    format!("q.block_state('{}') == {}", state, value.render())
+}
+
+fn render_joined(conditions: &[BlockCondition], separator: &str) -> String {
+    let rendered: Vec<String> = conditions
+        .iter()
+        .map(|condition| condition.render())
+        .collect();
+
+    match rendered.as_slice() {
+        [] => "true".to_string(),
+        [condition] => condition.clone(),
+        conditions => format!("({})", conditions.join(separator)),
+    }
+}
+
+/// The ids of the states a free-form Molang condition reads with
+/// `q.block_state`/`query.block_state`.
+pub fn molang_referenced_states(condition: &str) -> Vec<String> {
+    let regex = Regex::new(r#"(?:q|query)\.block_state\(\s*['"]([^'"]+)['"]\s*\)"#).unwrap();
+
+    regex
+        .captures_iter(condition)
+        .map(|captures| captures[1].to_string())
+        .collect()
+}
+
+/// A permutation condition that can never be evaluated as intended.
+#[derive(Clone, Debug)]
+pub enum BlockConditionError {
+    /// The condition reads a state the block doesn't declare.
+    UndeclaredState { condition: String, state: String },
+    /// The condition compares a state against a value it can't take.
+    ImpossibleValue {
+        condition: String,
+        state: String,
+        value: StateValue,
+    },
+}
+
+impl fmt::Display for BlockConditionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UndeclaredState { condition, state } => write!(
+                f,
+                "Condition \"{}\" references the undeclared state \"{}\"",
+                condition, state
+            ),
+            Self::ImpossibleValue {
+                condition,
+                state,
+                value,
+            } => write!(
+                f,
+                "Condition \"{}\" compares \"{}\" against {}, which isn't one of its values",
+                condition,
+                state,
+                value.render()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BlockConditionError {}
@@ -0,0 +1,60 @@
+use crate::block::component::{BlockComponent, BlockCraftingTableComponent};
+use crate::block::Block;
+use crate::pack::Pack;
+use crate::vio::Buildable;
+use std::sync::Arc;
+
+/// A block that opens a crafting grid for the recipes tagged with its
+/// crafting tag. Recipes are added to it with `using_station`.
+///
+/// ```ignore
+/// let station = CraftingStation::new(Block::new(Identifier::new("violin", "anvil")), "Anvil");
+/// station.register(&mut pack);
+///
+/// pack.register_recipe(
+///     ShapedRecipe::new(id, result)
+///         .using_station(&station)
+///         .build(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct CraftingStation {
+    pub block: Block,
+    /// The title of the crafting screen.
+    pub table_name: String,
+}
+
+impl CraftingStation {
+    pub fn new(block: Block, table_name: impl Into<String>) -> Self {
+        Self {
+            block,
+            table_name: table_name.into(),
+        }
+    }
+
+    /// The crafting tag of the station, its block id.
+    pub fn tag(&self) -> String {
+        self.block.type_id().render()
+    }
+
+    /// The block with a `minecraft:crafting_table` component for the tag,
+    /// replacing any it had.
+    pub fn block(&self) -> Block {
+        let mut components: Vec<Arc<dyn BlockComponent>> = self
+            .block
+            .components
+            .iter()
+            .filter(|component| component.id() != "minecraft:crafting_table")
+            .cloned()
+            .collect();
+        components.push(
+            BlockCraftingTableComponent::new(self.table_name.clone(), vec![self.tag()]).build(),
+        );
+
+        self.block.clone().using_components(components)
+    }
+
+    pub fn register(&self, pack: &mut Pack) {
+        pack.register_block(self.block());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::block::utils::BlockFace;
+use crate::vio::Identifier;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt;
+
+/// When a culling rule hides its geometry part.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CullingCondition {
+    /// Culled by any full, opaque block.
+    Default,
+    SameBlock,
+    SameBlockPermutation,
+    /// Culled by blocks whose geometry has the same `culling_layer`.
+    SameCullingLayer,
+}
+
+/// The part of the geometry a rule culls. Without `cube` the rule applies to
+/// every cube of the bone, without `face` to every face of the cube.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GeometryPart {
+    pub bone: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cube: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub face: Option<BlockFace>,
+}
+
+/// Hides a geometry part when the neighbour in `direction` covers it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CullingRule {
+    pub direction: BlockFace,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<CullingCondition>,
+    pub geometry_part: GeometryPart,
+}
+
+impl CullingRule {
+    pub fn new(direction: BlockFace, bone: impl Into<String>) -> Self {
+        Self {
+            direction,
+            condition: None,
+            geometry_part: GeometryPart {
+                bone: bone.into(),
+                cube: None,
+                face: None,
+            },
+        }
+    }
+
+    pub fn using_cube(mut self, cube: u32) -> Self {
+        self.geometry_part.cube = Some(cube);
+        self
+    }
+
+    pub fn using_face(mut self, face: BlockFace) -> Self {
+        self.geometry_part.face = Some(face);
+        self
+    }
+
+    pub fn using_condition(self, condition: CullingCondition) -> Self {
+        Self {
+            condition: Some(condition),
+            ..self
+        }
+    }
+}
+
+/// A `RP/block_culling` file, referenced by the `culling` of a block's
+/// `BlockGeometryComponent`.
+///
+/// ```ignore
+/// BlockCullingRules::new(Identifier::new("violin", "culling.slab"))
+///     .using_rule(CullingRule::new(BlockFace::Down, "bottom_slab").using_face(BlockFace::Down))
+///     .using_rule(
+///         CullingRule::new(BlockFace::Up, "top_slab")
+///             .using_condition(CullingCondition::SameCullingLayer),
+///     );
+/// ```
+#[derive(Clone, Debug)]
+pub struct BlockCullingRules {
+    pub id: Identifier,
+    pub rules: Vec<CullingRule>,
+}
+
+impl BlockCullingRules {
+    pub fn new(id: Identifier) -> Self {
+        Self { id, rules: vec![] }
+    }
+
+    pub fn using_rule(mut self, rule: CullingRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// The file name below `RP/block_culling`, e.g. `violin_culling.slab.json`.
+    pub fn file_name(&self) -> String {
+        format!("{}_{}.json", self.id.namespace, self.id.value)
+    }
+
+    pub fn value(&self) -> Value {
+        json!({
+            "format_version": "1.21.80",
+            "minecraft:block_culling_rules": {
+                "description": {
+                    "identifier": self.id.render()
+                },
+                "rules": self.rules
+            }
+        })
+    }
+}
+
+/// A problem found by `BlockRegistry::validate_culling_rules`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlockCullingIssue {
+    /// A block's geometry refers to culling rules that aren't registered.
+    UnregisteredRules { block: String, rules: String },
+    /// Two culling rules share an id.
+    DuplicateId { id: String },
+}
+
+impl fmt::Display for BlockCullingIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnregisteredRules { block, rules } => write!(
+                f,
+                "The geometry of block \"{}\" refers to the unregistered culling rules \"{}\"",
+                block, rules
+            ),
+            Self::DuplicateId { id } => {
+                write!(
+                    f,
+                    "More than one set of culling rules has the id \"{}\"",
+                    id
+                )
+            }
+        }
+    }
+}
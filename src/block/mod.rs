@@ -1,18 +1,31 @@
-use self::component::BlockComponent;
+use self::component::{block_component_from_json, BlockComponent};
+use crate::block::block_registry::BlockTexture;
+use crate::block::condition::{molang_referenced_states, BlockConditionError};
 use crate::block::permutation::BlockPermutation;
-use crate::block::state::BlockState;
-use crate::vio::{Generatable, Identifier, SemVer};
-use askama::Template;
-use jsonxf::pretty_print;
+use crate::block::state::{block_state_from_json, BlockState, StateValue};
+use crate::block::traits::{block_trait_from_json, BlockTrait};
+use crate::item::utils::MenuCategory;
+use crate::tag::Tag;
+use crate::vio::{
+    components_to_map, json_field, json_format_version, json_identifier,
+    DuplicateComponentError, Generatable, Identifier, ParseError, SemVer,
+};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 pub mod block_registry;
+pub mod building_set;
 pub mod component;
+pub mod condition;
+pub mod crafting_station;
+pub mod culling;
 
 pub mod permutation;
 pub mod state;
+pub mod traits;
 pub mod utils;
 
 #[derive(Clone)]
@@ -22,52 +35,162 @@ pub struct Block {
     pub components: Vec<Arc<dyn BlockComponent>>,
     pub permutations: Vec<BlockPermutation>,
     pub states: Vec<Arc<dyn BlockState>>,
+    pub traits: Vec<Arc<dyn BlockTrait>>,
+    pub menu_category: Option<MenuCategory>,
+    /// Textures registered with their terrain atlas entries when the block is
+    /// registered, see `using_textures`.
+    pub textures: Vec<BlockTexture>,
 }
 
 impl Block {
-    pub fn serialize(&self) -> String {
-        let components = self.components.clone();
-        let mut components_strings: Vec<String> = vec![];
-        for component in components {
-            let mut fser = component.serialize();
-            fser.push(',');
-            components_strings.push(fser);
+    /// The components as a JSON object, keyed by component id.
+    pub fn components_value(&self) -> Result<Map<String, Value>, DuplicateComponentError> {
+        components_to_map(
+            self.components
+                .iter()
+                .map(|component| (component.id(), component.value())),
+        )
+    }
+
+    pub fn to_value(&self) -> Result<Value, DuplicateComponentError> {
+        let mut states = Map::new();
+        for state in &self.states {
+            states.insert(state.id().render(), state.value());
         }
-        components_strings.last_mut().unwrap().pop();
 
-        let mut permutations = String::from("");
-        for perm in self.permutations.clone() {
-            permutations.push_str(perm.serialize().as_str());
-            permutations.push(',');
+        let mut description = Map::new();
+        description.insert("identifier".to_string(), json!(self.type_id.render()));
+        description.insert("states".to_string(), Value::Object(states));
+        description.insert(
+            "traits".to_string(),
+            Value::Object(components_to_map(
+                self.traits
+                    .iter()
+                    .map(|block_trait| (block_trait.id(), block_trait.value())),
+            )?),
+        );
+        if let Some(menu_category) = &self.menu_category {
+            description.insert("menu_category".to_string(), json!(menu_category));
         }
-        permutations.pop();
 
-        let mut states = String::from("");
-        for state in self.states.clone() {
-            states.push_str(state.serialize().as_str());
-            states.push(',');
+        let permutations = self
+            .permutations
+            .iter()
+            .map(|permutation| permutation.to_value())
+            .collect::<Result<Vec<Value>, DuplicateComponentError>>()?;
+
+        Ok(json!({
+            "format_version": self.format_version.render(),
+            "minecraft:block": {
+                "description": description,
+                "components": self.components_value()?,
+                "permutations": permutations
+            }
+        }))
+    }
+
+    /// Serializes the block, panicking if two components of the block or of
+    /// one of its permutations share the same id.
+    pub fn serialize(&self) -> String {
+        match self.to_value() {
+            Ok(value) => value.to_string(),
+            Err(err) => panic!("Block \"{}\": {}", self.type_id.render(), err),
         }
-        states.pop();
+    }
 
-        BlockTemplate {
-            type_id: self.clone().type_id.render(),
-            components: components_strings.join("\n"),
-            traits: "".to_string(),
-            states,
-            permutations,
-            format_version: self.format_version.render(),
+    /// Reads an existing block definition. Components are mapped as described
+    /// in `block_component_from_json`.
+    pub fn from_json(src: &str) -> Result<Self, ParseError> {
+        Self::from_value(&serde_json::from_str(src)?)
+    }
+
+    pub fn from_value(value: &Value) -> Result<Self, ParseError> {
+        let format_version = json_format_version(value)?;
+        let type_id = json_identifier(value, &["minecraft:block", "description", "identifier"])?;
+        let description = json_field(value, &["minecraft:block", "description"])?;
+
+        let menu_category = match description.get("menu_category") {
+            Some(menu_category) => Some(serde_json::from_value(menu_category.clone())?),
+            None => None,
+        };
+
+        let mut states: Vec<Arc<dyn BlockState>> = vec![];
+        if let Some(Value::Object(raw_states)) = description.get("states") {
+            for (id, values) in raw_states {
+                let state = Identifier::parse(id)
+                    .and_then(|state_id| block_state_from_json(state_id, values))
+                    .ok_or_else(|| ParseError::InvalidValue {
+                        field: format!("minecraft:block.description.states.{}", id),
+                        value: values.to_string(),
+                    })?;
+                states.push(state);
+            }
         }
-        .render()
-        .unwrap()
+
+        let traits = match description.get("traits") {
+            Some(Value::Object(traits)) => traits
+                .iter()
+                .map(|(id, block_trait)| block_trait_from_json(id, block_trait))
+                .collect(),
+            Some(traits) => {
+                return Err(ParseError::InvalidValue {
+                    field: "minecraft:block.description.traits".to_string(),
+                    value: traits.to_string(),
+                })
+            }
+            None => vec![],
+        };
+
+        let components = match value["minecraft:block"].get("components") {
+            Some(Value::Object(components)) => components
+                .iter()
+                .map(|(id, component)| block_component_from_json(id, component))
+                .collect(),
+            Some(components) => {
+                return Err(ParseError::InvalidValue {
+                    field: "minecraft:block.components".to_string(),
+                    value: components.to_string(),
+                })
+            }
+            None => vec![],
+        };
+
+        let permutations = match value["minecraft:block"].get("permutations") {
+            Some(Value::Array(permutations)) => permutations
+                .iter()
+                .map(BlockPermutation::from_value)
+                .collect::<Result<Vec<BlockPermutation>, ParseError>>()?,
+            Some(permutations) => {
+                return Err(ParseError::InvalidValue {
+                    field: "minecraft:block.permutations".to_string(),
+                    value: permutations.to_string(),
+                })
+            }
+            None => vec![],
+        };
+
+        Ok(Self {
+            type_id,
+            format_version,
+            components,
+            permutations,
+            states,
+            traits,
+            menu_category,
+            textures: vec![],
+        })
     }
 
     pub fn new(type_id: Identifier) -> Self {
         Self {
             type_id,
             states: vec![],
+            traits: vec![],
             permutations: vec![],
             components: vec![],
             format_version: SemVer::current(),
+            menu_category: None,
+            textures: vec![],
         }
     }
 
@@ -85,6 +208,234 @@ impl Block {
         sc
     }
 
+    pub fn using_traits(&mut self, traits: Vec<Arc<dyn BlockTrait>>) -> Self {
+        let mut sc = self.clone();
+        sc.traits = traits;
+
+        sc
+    }
+
+    /// Declares the textures the block's material instances use. They're
+    /// added to the pack's textures and terrain atlas along with the block,
+    /// so they don't need to be registered separately.
+    pub fn using_textures(&mut self, textures: Vec<BlockTexture>) -> Self {
+        let mut sc = self.clone();
+        sc.textures = textures;
+
+        sc
+    }
+
+    /// The states added by the block's traits, e.g. `minecraft:cardinal_direction`.
+    pub fn trait_states(&self) -> Vec<Arc<dyn BlockState>> {
+        self.traits
+            .iter()
+            .flat_map(|block_trait| block_trait.states())
+            .collect()
+    }
+
+    /// The textures the material instances of the block and its permutations
+    /// refer to.
+    pub fn material_instance_textures(&self) -> Vec<String> {
+        self.components
+            .iter()
+            .chain(
+                self.permutations
+                    .iter()
+                    .flat_map(|permutation| permutation.components.iter()),
+            )
+            .filter(|component| component.id() == "minecraft:material_instances")
+            .flat_map(|component| match component.value() {
+                Value::Object(instances) => instances
+                    .values()
+                    .filter_map(|instance| instance["texture"].as_str().map(String::from))
+                    .collect(),
+                _ => vec![],
+            })
+            .collect()
+    }
+
+    /// The ids of the culling rules the geometry of the block and its
+    /// permutations refers to.
+    pub fn geometry_culling_rules(&self) -> Vec<String> {
+        self.components
+            .iter()
+            .chain(
+                self.permutations
+                    .iter()
+                    .flat_map(|permutation| permutation.components.iter()),
+            )
+            .filter(|component| component.id() == "minecraft:geometry")
+            .filter_map(|component| component.value()["culling"].as_str().map(String::from))
+            .collect()
+    }
+
+    /// The tags of the block's `tag:` components.
+    pub fn tags(&self) -> Vec<Tag> {
+        self.components
+            .iter()
+            .chain(
+                self.permutations
+                    .iter()
+                    .flat_map(|permutation| permutation.components.iter()),
+            )
+            .filter_map(|component| component.id().strip_prefix("tag:").map(Tag::new))
+            .collect()
+    }
+
+    /// The crafting tags of the block's `minecraft:crafting_table` components.
+    pub fn crafting_tags(&self) -> Vec<String> {
+        self.components
+            .iter()
+            .chain(
+                self.permutations
+                    .iter()
+                    .flat_map(|permutation| permutation.components.iter()),
+            )
+            .filter(|component| component.id() == "minecraft:crafting_table")
+            .flat_map(|component| match &component.value()["crafting_tags"] {
+                Value::Array(tags) => tags
+                    .iter()
+                    .filter_map(|tag| tag.as_str().map(String::from))
+                    .collect(),
+                _ => vec![],
+            })
+            .collect()
+    }
+
+    /// Checks the permutation conditions against the declared and trait
+    /// states. Typed conditions are also checked for values the state can't
+    /// take; string conditions only for undeclared states. States in the
+    /// namespace of a trait with unknown states, like an imported raw trait,
+    /// count as declared.
+    pub fn validate(&self) -> Result<(), Vec<BlockConditionError>> {
+        let states: Vec<Arc<dyn BlockState>> = self
+            .states
+            .iter()
+            .cloned()
+            .chain(self.trait_states())
+            .collect();
+        let find_state = |id: &str| states.iter().find(|state| state.id().render() == id);
+        let unknown_namespaces: Vec<String> = self
+            .traits
+            .iter()
+            .filter(|block_trait| block_trait.has_unknown_states())
+            .filter_map(|block_trait| {
+                block_trait
+                    .id()
+                    .split_once(':')
+                    .map(|(namespace, _)| namespace.to_string())
+            })
+            .collect();
+        let is_undeclared = |id: &str| {
+            !id.split_once(':')
+                .is_some_and(|(namespace, _)| unknown_namespaces.iter().any(|n| n == namespace))
+        };
+
+        let mut errors = vec![];
+        for permutation in &self.permutations {
+            let condition = permutation.condition.clone();
+
+            match &permutation.typed_condition {
+                Some(typed_condition) => {
+                    for (id, value) in typed_condition.referenced_values() {
+                        match find_state(&id) {
+                            Some(state) if !state.values().contains(&value) => {
+                                errors.push(BlockConditionError::ImpossibleValue {
+                                    condition: condition.clone(),
+                                    state: id,
+                                    value,
+                                })
+                            }
+                            Some(_) => {}
+                            None if is_undeclared(&id) => {
+                                errors.push(BlockConditionError::UndeclaredState {
+                                    condition: condition.clone(),
+                                    state: id,
+                                })
+                            }
+                            None => {}
+                        }
+                    }
+                }
+                None => {
+                    for id in molang_referenced_states(&condition) {
+                        if find_state(&id).is_none() && is_undeclared(&id) {
+                            errors.push(BlockConditionError::UndeclaredState {
+                                condition: condition.clone(),
+                                state: id,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// The number of state combinations of the block, i.e. the product of the
+    /// value counts of its declared and trait states. Saturates at `u64::MAX`.
+    pub fn state_combinations(&self) -> u64 {
+        self.states
+            .iter()
+            .cloned()
+            .chain(self.trait_states())
+            .fold(1u64, |total, state| {
+                total.saturating_mul(state.values().len() as u64)
+            })
+    }
+
+    /// Permutations whose typed condition holds for none of the combinations
+    /// of the states it references. String conditions, conditions referencing
+    /// undeclared states and ones over more than 65,536 combinations aren't
+    /// checked.
+    pub fn unmatchable_permutations(&self) -> Vec<&BlockPermutation> {
+        let states: Vec<Arc<dyn BlockState>> = self
+            .states
+            .iter()
+            .cloned()
+            .chain(self.trait_states())
+            .collect();
+
+        self.permutations
+            .iter()
+            .filter(|permutation| {
+                let Some(condition) = &permutation.typed_condition else {
+                    return false;
+                };
+
+                let mut referenced: Vec<(String, Vec<StateValue>)> = vec![];
+                for (id, _) in condition.referenced_values() {
+                    if referenced
+                        .iter()
+                        .any(|(referenced_id, _)| referenced_id == &id)
+                    {
+                        continue;
+                    }
+                    match states.iter().find(|state| state.id().render() == id) {
+                        Some(state) => referenced.push((id, state.values())),
+                        None => return false,
+                    }
+                }
+
+                let combinations = referenced.iter().fold(1u64, |total, (_, values)| {
+                    total.saturating_mul(values.len() as u64)
+                });
+                if combinations > 65_536 {
+                    return false;
+                }
+
+                !state_combinations(&referenced)
+                    .iter()
+                    .any(|combination| condition.matches(combination))
+            })
+            .collect()
+    }
+
     pub fn using_permutations(&mut self, permutations: Vec<BlockPermutation>) -> Self {
         let mut sc = self.clone();
         sc.permutations = permutations;
@@ -99,24 +450,42 @@ impl Block {
         sc
     }
 
+    pub fn using_menu_category(&self, menu_category: MenuCategory) -> Self {
+        let mut sc = self.clone();
+        sc.menu_category = Some(menu_category);
+
+        sc
+    }
+
     pub fn type_id(&self) -> Identifier {
         self.type_id.clone()
     }
 }
 
+/// Every assignment of a value to each of the states.
+fn state_combinations(states: &[(String, Vec<StateValue>)]) -> Vec<HashMap<String, StateValue>> {
+    states
+        .iter()
+        .fold(vec![HashMap::new()], |combinations, (id, values)| {
+            combinations
+                .iter()
+                .flat_map(|combination| {
+                    values.iter().map(move |value| {
+                        let mut combination = combination.clone();
+                        combination.insert(id.clone(), value.clone());
+                        combination
+                    })
+                })
+                .collect()
+        })
+}
+
 impl Generatable for Block {
     fn generate(&self, path_buf: impl Into<PathBuf>) {
-        fs::write(path_buf.into(), pretty_print(&self.serialize()).unwrap()).unwrap();
+        fs::write(
+            path_buf.into(),
+            serde_json::to_string_pretty(&self.to_value().unwrap()).unwrap(),
+        )
+        .unwrap();
     }
 }
-
-#[derive(Template)]
-#[template(path = "block_serialization/block.json.jinja2", escape = "none")]
-struct BlockTemplate {
-    type_id: String,
-    components: String,
-    traits: String,
-    permutations: String,
-    states: String,
-    format_version: String,
-}
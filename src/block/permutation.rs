@@ -1,10 +1,15 @@
-use crate::block::component::BlockComponent;
-use askama::Template;
-use std::sync::{Arc, Mutex};
+use crate::block::component::{block_component_from_json, BlockComponent};
+use crate::block::condition::BlockCondition;
+use crate::vio::{components_to_map, json_field, DuplicateComponentError, ParseError};
+use serde_json::{json, Value};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct BlockPermutation {
     pub condition: String,
+    /// The condition `condition` was rendered from, if built with `new_typed`.
+    /// Checked against the block's states when the pack is generated.
+    pub typed_condition: Option<BlockCondition>,
     pub components: Vec<Arc<dyn BlockComponent>>,
 }
 
@@ -13,38 +18,55 @@ impl BlockPermutation {
         Self {
             components,
             condition: condition.into(),
+            typed_condition: None,
         }
     }
-}
 
-#[derive(Template)]
-#[template(path = "block_serialization/permutation.json.jinja2", escape = "none")]
-struct BlockPermutationTemplate {
-    condition: String,
-    components: String,
+    pub fn new_typed(condition: BlockCondition, components: Vec<Arc<dyn BlockComponent>>) -> Self {
+        Self {
+            components,
+            condition: condition.render(),
+            typed_condition: Some(condition),
+        }
+    }
 }
 
 impl BlockPermutation {
-    pub fn serialize(&self) -> String {
-        let components = self.components.clone();
-        let mut components_strings: Vec<String> = vec![];
-        for component in components {
-            let comp = Mutex::new(component);
-            let ser = comp.lock();
-            let mut fser = match ser {
-                Ok(guard) => guard.serialize(),
-                Err(_) => "Error serializing".to_string(),
-            };
-            fser.push(',');
-            components_strings.push(fser);
-        }
-        components_strings.last_mut().unwrap().pop();
-        let final_components = components_strings.join("\n").to_string();
-        BlockPermutationTemplate {
-            condition: self.condition.to_string(),
-            components: final_components,
-        }
-        .render()
-        .unwrap()
+    pub fn from_value(value: &Value) -> Result<Self, ParseError> {
+        let condition = json_field(value, &["condition"])?;
+        let condition = condition
+            .as_str()
+            .ok_or_else(|| ParseError::InvalidValue {
+                field: "condition".to_string(),
+                value: condition.to_string(),
+            })?;
+
+        let components = match json_field(value, &["components"])? {
+            Value::Object(components) => components
+                .iter()
+                .map(|(id, component)| block_component_from_json(id, component))
+                .collect(),
+            components => {
+                return Err(ParseError::InvalidValue {
+                    field: "components".to_string(),
+                    value: components.to_string(),
+                })
+            }
+        };
+
+        Ok(Self::new(condition, components))
+    }
+
+    pub fn to_value(&self) -> Result<Value, DuplicateComponentError> {
+        let components = components_to_map(
+            self.components
+                .iter()
+                .map(|component| (component.id(), component.value())),
+        )?;
+
+        Ok(json!({
+            "condition": self.condition,
+            "components": components
+        }))
     }
 }
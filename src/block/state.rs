@@ -1,8 +1,56 @@
 use crate::vio::{Buildable, Identifier};
-use askama::Template;
+use serde_json::{json, Value};
+use std::sync::Arc;
 
 pub trait BlockState {
-    fn serialize(&self) -> String;
+    fn id(&self) -> Identifier;
+    /// The valid values of the state, as written under `description.states`.
+    fn value(&self) -> Value;
+    /// Every value the state can take.
+    fn values(&self) -> Vec<StateValue>;
+}
+
+/// A value of a block state, as compared against in permutation conditions.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StateValue {
+    Bool(bool),
+    Int(i32),
+    Str(String),
+}
+
+impl StateValue {
+    /// The value as a Molang literal.
+    pub fn render(&self) -> String {
+        match self {
+            Self::Bool(value) => value.to_string(),
+            Self::Int(value) => value.to_string(),
+            Self::Str(value) => format!("'{}'", value),
+        }
+    }
+}
+
+impl From<bool> for StateValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for StateValue {
+    fn from(value: i32) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<&str> for StateValue {
+    fn from(value: &str) -> Self {
+        Self::Str(value.to_string())
+    }
+}
+
+impl From<String> for StateValue {
+    fn from(value: String) -> Self {
+        Self::Str(value)
+    }
 }
 
 #[derive(Clone)]
@@ -12,22 +60,17 @@ pub struct BoolBlockState {
 
 impl Buildable for BoolBlockState {}
 
-#[derive(Template)]
-#[template(
-    path = "block_serialization/state/bool_state.json.jinja2",
-    escape = "none"
-)]
-struct BoolBlockStateTemplate {
-    identifier: String,
-}
-
 impl BlockState for BoolBlockState {
-    fn serialize(&self) -> String {
-        BoolBlockStateTemplate {
-            identifier: self.id.render().to_string(),
-        }
-        .render()
-        .unwrap()
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+
+    fn value(&self) -> Value {
+        json!([true, false])
+    }
+
+    fn values(&self) -> Vec<StateValue> {
+        vec![StateValue::Bool(true), StateValue::Bool(false)]
     }
 }
 
@@ -45,24 +88,17 @@ impl NumericBlockState {
     }
 }
 
-#[derive(Template)]
-#[template(
-    path = "block_serialization/state/numeric_state.json.jinja2",
-    escape = "none"
-)]
-struct VariableBlockStateTemplate {
-    identifier: String,
-    values: String,
-}
-
 impl BlockState for NumericBlockState {
-    fn serialize(&self) -> String {
-        VariableBlockStateTemplate {
-            identifier: self.id.render().to_string(),
-            values: format!("{:?}", self.values),
-        }
-        .render()
-        .unwrap()
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+
+    fn value(&self) -> Value {
+        json!(self.values)
+    }
+
+    fn values(&self) -> Vec<StateValue> {
+        self.values.iter().map(|value| StateValue::Int(*value)).collect()
     }
 }
 
@@ -84,13 +120,19 @@ impl StringBlockState {
 }
 
 impl BlockState for StringBlockState {
-    fn serialize(&self) -> String {
-        VariableBlockStateTemplate {
-            identifier: self.id.render().to_string(),
-            values: format!("{:?}", self.values),
-        }
-        .render()
-        .unwrap()
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+
+    fn value(&self) -> Value {
+        json!(self.values)
+    }
+
+    fn values(&self) -> Vec<StateValue> {
+        self.values
+            .iter()
+            .map(|value| StateValue::Str(value.clone()))
+            .collect()
     }
 }
 
@@ -109,25 +151,53 @@ impl RangedBlockState {
     }
 }
 
-#[derive(Template)]
-#[template(
-    path = "block_serialization/state/ranged_state.json.jinja2",
-    escape = "none"
-)]
-struct RangedBlockStateTemplate {
-    identifier: String,
-    min: i32,
-    max: i32,
+impl BlockState for RangedBlockState {
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+
+    fn value(&self) -> Value {
+        json!({
+            "values": { "min": self.min, "max": self.max }
+        })
+    }
+
+    fn values(&self) -> Vec<StateValue> {
+        (self.min..=self.max).map(StateValue::Int).collect()
+    }
 }
 
-impl BlockState for RangedBlockState {
-    fn serialize(&self) -> String {
-        RangedBlockStateTemplate {
-            identifier: self.id.render().to_string(),
-            min: self.min,
-            max: self.max,
-        }
-        .render()
-        .unwrap()
+/// Reads a state of an existing block's `description.states`. Returns `None`
+/// if the values don't match any supported kind of state.
+pub fn block_state_from_json(id: Identifier, value: &Value) -> Option<Arc<dyn BlockState>> {
+    if let Some(range) = value.get("values") {
+        let min = range.get("min")?.as_i64()? as i32;
+        let max = range.get("max")?.as_i64()? as i32;
+
+        return Some(RangedBlockState::new(id, min, max).build());
+    }
+
+    let values = value.as_array()?;
+
+    if values.iter().all(Value::is_boolean) {
+        Some(BoolBlockState { id }.build())
+    } else if values.iter().all(Value::is_i64) {
+        Some(
+            NumericBlockState::new(
+                id,
+                values.iter().map(|v| v.as_i64().unwrap() as i32).collect(),
+            )
+            .build(),
+        )
+    } else if values.iter().all(Value::is_string) {
+        Some(
+            StringBlockState::new(
+                id,
+                values.iter().map(|v| v.as_str().unwrap()).collect(),
+            )
+            .build(),
+        )
+    } else {
+        None
     }
 }
@@ -0,0 +1,284 @@
+use crate::block::state::{BlockState, BoolBlockState, StringBlockState};
+use crate::vio::{json_equivalent, Buildable, Identifier};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+
+pub trait BlockTrait {
+    /// The key of the trait, e.g. `minecraft:placement_direction`.
+    fn id(&self) -> String;
+    fn value(&self) -> Value;
+    /// The states the game adds to the block for this trait, so permutations
+    /// can reference them.
+    fn states(&self) -> Vec<Arc<dyn BlockState>>;
+    /// Whether the trait may add states missing from `states`. Permutations
+    /// referencing undeclared states in the trait's namespace are then
+    /// assumed to use them.
+    fn has_unknown_states(&self) -> bool {
+        false
+    }
+}
+
+// * PlacementDirectionTrait
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PlacementDirectionState {
+    /// `north`, `south`, `east` or `west`, depending on the player's rotation.
+    #[serde(rename = "minecraft:cardinal_direction")]
+    CardinalDirection,
+    /// Like `CardinalDirection`, with `up` and `down` as well.
+    #[serde(rename = "minecraft:facing_direction")]
+    FacingDirection,
+}
+
+impl PlacementDirectionState {
+    pub fn id(&self) -> Identifier {
+        match self {
+            Self::CardinalDirection => Identifier::new("minecraft", "cardinal_direction"),
+            Self::FacingDirection => Identifier::new("minecraft", "facing_direction"),
+        }
+    }
+
+    /// The state added to the block, for use in permutation conditions.
+    pub fn state(&self) -> Arc<dyn BlockState> {
+        match self {
+            Self::CardinalDirection => {
+                StringBlockState::new(self.id(), vec!["north", "south", "east", "west"]).build()
+            }
+            Self::FacingDirection => StringBlockState::new(
+                self.id(),
+                vec!["down", "up", "north", "south", "east", "west"],
+            )
+            .build(),
+        }
+    }
+}
+
+/// Records the direction the player faced when placing the block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlacementDirectionTrait {
+    pub enabled_states: Vec<PlacementDirectionState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y_rotation_offset: Option<f64>,
+}
+
+impl PlacementDirectionTrait {
+    pub fn new(enabled_states: Vec<PlacementDirectionState>) -> Self {
+        Self {
+            enabled_states,
+            y_rotation_offset: None,
+        }
+    }
+
+    /// Rotation added to the player's, one of `0`, `90`, `180` or `270`.
+    pub fn using_y_rotation_offset(self, y_rotation_offset: f64) -> Self {
+        Self {
+            y_rotation_offset: Some(y_rotation_offset),
+            ..self
+        }
+    }
+}
+
+impl BlockTrait for PlacementDirectionTrait {
+    fn id(&self) -> String {
+        "minecraft:placement_direction".to_string()
+    }
+
+    fn value(&self) -> Value {
+        serde_json::to_value(self).unwrap()
+    }
+
+    fn states(&self) -> Vec<Arc<dyn BlockState>> {
+        self.enabled_states
+            .iter()
+            .map(|state| state.state())
+            .collect()
+    }
+}
+
+impl Buildable for PlacementDirectionTrait {}
+
+// * PlacementPositionTrait
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PlacementPositionState {
+    /// The face of the block the new block was placed against.
+    #[serde(rename = "minecraft:block_face")]
+    BlockFace,
+    /// `bottom` or `top`, the half of the block space that was clicked.
+    #[serde(rename = "minecraft:vertical_half")]
+    VerticalHalf,
+}
+
+impl PlacementPositionState {
+    pub fn id(&self) -> Identifier {
+        match self {
+            Self::BlockFace => Identifier::new("minecraft", "block_face"),
+            Self::VerticalHalf => Identifier::new("minecraft", "vertical_half"),
+        }
+    }
+
+    /// The state added to the block, for use in permutation conditions.
+    pub fn state(&self) -> Arc<dyn BlockState> {
+        match self {
+            Self::BlockFace => StringBlockState::new(
+                self.id(),
+                vec!["down", "up", "north", "south", "east", "west"],
+            )
+            .build(),
+            Self::VerticalHalf => StringBlockState::new(self.id(), vec!["bottom", "top"]).build(),
+        }
+    }
+}
+
+/// Records where the block was placed against.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlacementPositionTrait {
+    pub enabled_states: Vec<PlacementPositionState>,
+}
+
+impl PlacementPositionTrait {
+    pub fn new(enabled_states: Vec<PlacementPositionState>) -> Self {
+        Self { enabled_states }
+    }
+}
+
+impl BlockTrait for PlacementPositionTrait {
+    fn id(&self) -> String {
+        "minecraft:placement_position".to_string()
+    }
+
+    fn value(&self) -> Value {
+        serde_json::to_value(self).unwrap()
+    }
+
+    fn states(&self) -> Vec<Arc<dyn BlockState>> {
+        self.enabled_states
+            .iter()
+            .map(|state| state.state())
+            .collect()
+    }
+}
+
+impl Buildable for PlacementPositionTrait {}
+
+// * ConnectionTrait
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Adds `minecraft:connection_north`, `_east`, `_south` and `_west`,
+    /// each `true` when the neighbouring block connects to this one.
+    #[serde(rename = "minecraft:cardinal_connections")]
+    CardinalConnections,
+}
+
+impl ConnectionState {
+    /// The ids of the states added by this value.
+    pub fn ids(&self) -> Vec<Identifier> {
+        match self {
+            Self::CardinalConnections => ["north", "east", "south", "west"]
+                .iter()
+                .map(|direction| Identifier::new("minecraft", format!("connection_{}", direction)))
+                .collect(),
+        }
+    }
+}
+
+/// Tracks which neighbouring blocks connect to the block, like fences do.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConnectionTrait {
+    pub enabled_states: Vec<ConnectionState>,
+}
+
+impl ConnectionTrait {
+    pub fn new(enabled_states: Vec<ConnectionState>) -> Self {
+        Self { enabled_states }
+    }
+}
+
+impl BlockTrait for ConnectionTrait {
+    fn id(&self) -> String {
+        "minecraft:connection".to_string()
+    }
+
+    fn value(&self) -> Value {
+        serde_json::to_value(self).unwrap()
+    }
+
+    fn states(&self) -> Vec<Arc<dyn BlockState>> {
+        self.enabled_states
+            .iter()
+            .flat_map(|state| state.ids())
+            .map(|id| BoolBlockState { id }.build() as Arc<dyn BlockState>)
+            .collect()
+    }
+}
+
+impl Buildable for ConnectionTrait {}
+
+// * BlockRawTrait
+
+/// A trait Violin has no typed struct for, kept as raw JSON. Its states are
+/// unknown.
+#[derive(Clone, Debug)]
+pub struct BlockRawTrait {
+    pub id: String,
+    pub value: Value,
+}
+
+impl BlockRawTrait {
+    pub fn new(id: impl Into<String>, value: Value) -> Self {
+        Self {
+            id: id.into(),
+            value,
+        }
+    }
+}
+
+impl BlockTrait for BlockRawTrait {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn value(&self) -> Value {
+        self.value.clone()
+    }
+
+    fn states(&self) -> Vec<Arc<dyn BlockState>> {
+        vec![]
+    }
+
+    fn has_unknown_states(&self) -> bool {
+        true
+    }
+}
+
+impl Buildable for BlockRawTrait {}
+
+/// Reads a trait of an existing block's `description.traits`. Unknown traits,
+/// or ones the typed struct can't represent without loss, are kept as a
+/// `BlockRawTrait`.
+pub fn block_trait_from_json(id: &str, value: &Value) -> Arc<dyn BlockTrait> {
+    let typed = match id {
+        "minecraft:placement_direction" => typed_block_trait::<PlacementDirectionTrait>(value),
+        "minecraft:placement_position" => typed_block_trait::<PlacementPositionTrait>(value),
+        "minecraft:connection" => typed_block_trait::<ConnectionTrait>(value),
+        _ => None,
+    };
+
+    typed.unwrap_or_else(|| BlockRawTrait::new(id, value.clone()).build())
+}
+
+fn typed_block_trait<T>(value: &Value) -> Option<Arc<dyn BlockTrait>>
+where
+    T: BlockTrait + DeserializeOwned + 'static,
+{
+    let block_trait: T = serde_json::from_value(value.clone()).ok()?;
+
+    if json_equivalent(&block_trait.value(), value) {
+        Some(Arc::new(block_trait))
+    } else {
+        None
+    }
+}
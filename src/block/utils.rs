@@ -1,9 +1,10 @@
-use crate::vio::{Identifier, MolangStatement};
+use crate::block::block_registry::BlockTexture;
+use crate::vio::{Identifier, MolangStatement, RangeDescriptor};
 use derive_setters::Setters;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BlockDestroySpeed {
     block: BlockDescriptor,
     speed: i32,
@@ -15,11 +16,12 @@ impl BlockDestroySpeed {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Setters)]
+#[derive(Clone, Debug, Serialize, Deserialize, Setters)]
 #[setters(prefix = "using_")]
 pub struct BlockDescriptor {
     tags: Option<MolangStatement>,
     name: Option<Identifier>,
+    #[serde(default)]
     states: HashMap<String, String>,
 }
 
@@ -41,7 +43,7 @@ impl BlockDescriptor {
     }
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum BlockRenderMethod {
     AlphaTest,
@@ -51,7 +53,7 @@ pub enum BlockRenderMethod {
     Opaque,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum BlockFace {
     North,
@@ -62,7 +64,7 @@ pub enum BlockFace {
     Down,
 }
 
-#[derive(Clone, Debug, Serialize, Setters)]
+#[derive(Clone, Debug, Serialize, Deserialize, Setters)]
 #[setters(prefix = "using_")]
 pub struct MaterialInstance {
     pub texture: String,
@@ -80,9 +82,13 @@ impl MaterialInstance {
             ambient_occlusion: true,
         }
     }
+
+    pub fn from_texture(texture: &BlockTexture, render_method: BlockRenderMethod) -> Self {
+        Self::new(texture.id().render(), render_method)
+    }
 }
 
-#[derive(Clone, Debug, Serialize, Setters)]
+#[derive(Clone, Debug, Serialize, Deserialize, Setters)]
 #[setters(prefix = "using_")]
 pub struct BlockPlacementCondition {
     allowed_faces: Vec<BlockFace>,
@@ -97,3 +103,97 @@ impl BlockPlacementCondition {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ParticleTintMethod {
+    None,
+    DefaultFoliage,
+    BirchFoliage,
+    EvergreenFoliage,
+    DryFoliage,
+    Grass,
+    Water,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum LiquidType {
+    Water,
+}
+
+/// What happens to a block when liquid flows into it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum LiquidReaction {
+    Blocking,
+    Broken,
+    Popped,
+    NoReaction,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct LiquidDetectionRule {
+    pub liquid_type: LiquidType,
+    pub can_contain_liquid: bool,
+    pub on_liquid_touches: LiquidReaction,
+    /// Faces liquid can't flow out of the block through.
+    pub stops_liquid_flowing_from_direction: Vec<BlockFace>,
+    pub use_liquid_clipping: bool,
+}
+
+impl LiquidDetectionRule {
+    pub fn new(can_contain_liquid: bool, on_liquid_touches: LiquidReaction) -> Self {
+        Self {
+            liquid_type: LiquidType::Water,
+            can_contain_liquid,
+            on_liquid_touches,
+            stops_liquid_flowing_from_direction: Vec::new(),
+            use_liquid_clipping: false,
+        }
+    }
+}
+
+/// How pistons move a block.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockMovementType {
+    PushPull,
+    Push,
+    Immovable,
+    Popped,
+}
+
+/// Whether a block moved by a sticky piston pulls its neighbours along.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockStickyType {
+    Same,
+    None,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum PrecipitationBehavior {
+    ObstructRainAccumulateSnow,
+    ObstructRain,
+    None,
+}
+
+/// The offset of a block's model along one axis, picked per position from
+/// `steps` evenly spaced values within `range`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RandomOffsetAxis {
+    pub steps: u32,
+    pub range: RangeDescriptor<f64>,
+}
+
+impl RandomOffsetAxis {
+    pub fn new(steps: u32, min: f64, max: f64) -> Self {
+        Self {
+            steps,
+            range: RangeDescriptor::new(min, max),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::vio::{Identifier, SemVer, Vec3};
+use derive_setters::Setters;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CameraListener {
+    Player,
+}
+
+/// A camera preset, written to `BP/cameras/presets`. Presets can be used with
+/// the `/camera` command once the pack is applied.
+#[derive(Clone, Debug, Setters)]
+#[setters(prefix = "using_")]
+pub struct CameraPreset {
+    #[setters(skip)]
+    id: Identifier,
+    pub format_version: SemVer,
+    pub inherit_from: Identifier,
+    #[setters(strip_option)]
+    pub position: Option<Vec3>,
+    #[setters(strip_option)]
+    pub rotation: Option<[f64; 2]>,
+    #[setters(strip_option)]
+    pub listener: Option<CameraListener>,
+    #[setters(strip_option)]
+    pub player_effects: Option<bool>,
+    #[setters(strip_option)]
+    pub extend_player_rendering: Option<bool>,
+}
+
+impl CameraPreset {
+    pub fn new(id: Identifier, inherit_from: Identifier) -> Self {
+        Self {
+            id,
+            format_version: SemVer::new(1, 19, 50),
+            inherit_from,
+            position: None,
+            rotation: None,
+            listener: None,
+            player_effects: None,
+            extend_player_rendering: None,
+        }
+    }
+
+    /// A preset inheriting from `minecraft:free`.
+    pub fn free(id: Identifier) -> Self {
+        Self::new(id, Identifier::new("minecraft", "free"))
+    }
+
+    pub fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut preset = Map::new();
+        preset.insert("identifier".to_string(), json!(self.id.render()));
+        preset.insert("inherit_from".to_string(), json!(self.inherit_from.render()));
+
+        if let Some(position) = self.position {
+            preset.insert("pos_x".to_string(), json!(position.x));
+            preset.insert("pos_y".to_string(), json!(position.y));
+            preset.insert("pos_z".to_string(), json!(position.z));
+        }
+        if let Some([rot_x, rot_y]) = self.rotation {
+            preset.insert("rot_x".to_string(), json!(rot_x));
+            preset.insert("rot_y".to_string(), json!(rot_y));
+        }
+        if let Some(listener) = &self.listener {
+            preset.insert("listener".to_string(), json!(listener));
+        }
+        if let Some(player_effects) = self.player_effects {
+            preset.insert("player_effects".to_string(), json!(player_effects));
+        }
+        if let Some(extend_player_rendering) = self.extend_player_rendering {
+            preset.insert(
+                "extend_player_rendering".to_string(),
+                json!(extend_player_rendering),
+            );
+        }
+
+        json!({
+            "format_version": self.format_version.render(),
+            "minecraft:camera_preset": Value::Object(preset)
+        })
+        .to_string()
+    }
+}
@@ -0,0 +1,899 @@
+//! Generates Violin source code from an existing behavior and resource pack,
+//! see `generate_pack_source`.
+
+use crate::block::component::{
+    BlockComponent, BlockDestructibleByExplosionComponent, BlockDestructibleByMiningComponent,
+    BlockDisplayNameComponent, BlockEntityFallOnComponent, BlockFrictionComponent,
+    BlockLightDampeningComponent, BlockLightEmissionComponent, BlockLootComponent,
+};
+use crate::block::traits::{
+    BlockTrait, ConnectionTrait, PlacementDirectionTrait, PlacementPositionTrait,
+};
+use crate::block::Block;
+use crate::import::{
+    find_texture, pack_files, read_block_atlas_entry, read_json, read_texture_data,
+};
+use crate::item::component::{
+    ItemAllowOffHandComponent, ItemArmorComponent, ItemBundleInteractionComponent,
+    ItemCanDestroyInCreativeComponent, ItemComponent, ItemCompostableComponent,
+    ItemDamageComponent, ItemDisplayNameComponent, ItemFuelComponent, ItemGlintComponent,
+    ItemHandEquippedComponent, ItemLiquidClippedComponent, ItemMaxStackSizeComponent,
+    ItemShouldDespawnComponent, ItemStackedByDataComponent, ItemSwingDurationComponent,
+};
+use crate::item::utils::MenuCategory;
+use crate::item::Item;
+use crate::recipe::{FurnaceRecipe, RecipeIO, ShapedRecipe, ShapelessRecipe};
+use crate::vio::{json_equivalent, Identifier, ParseError, SemVer};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+enum ArgumentKind {
+    Bool,
+    Int,
+    Float,
+    Str,
+}
+
+/// A component that is written as `Name::new(argument)` when its JSON is a
+/// single value, or an object with a single field.
+struct TypedComponent {
+    id: &'static str,
+    name: &'static str,
+    kind: ArgumentKind,
+    /// Builds the component from the argument, returning its JSON.
+    value: fn(&Value) -> Option<Value>,
+}
+
+const ITEM_COMPONENTS: &[TypedComponent] = &[
+    TypedComponent {
+        id: "minecraft:damage",
+        name: "ItemDamageComponent",
+        kind: ArgumentKind::Int,
+        value: |arg| Some(ItemDamageComponent::new(arg.as_i64()?.try_into().ok()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:display_name",
+        name: "ItemDisplayNameComponent",
+        kind: ArgumentKind::Str,
+        value: |arg| Some(ItemDisplayNameComponent::new(arg.as_str()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:fuel",
+        name: "ItemFuelComponent",
+        kind: ArgumentKind::Int,
+        value: |arg| Some(ItemFuelComponent::new(arg.as_i64()?.try_into().ok()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:hand_equipped",
+        name: "ItemHandEquippedComponent",
+        kind: ArgumentKind::Bool,
+        value: |arg| Some(ItemHandEquippedComponent::new(arg.as_bool()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:allow_off_hand",
+        name: "ItemAllowOffHandComponent",
+        kind: ArgumentKind::Bool,
+        value: |arg| Some(ItemAllowOffHandComponent::new(arg.as_bool()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:max_stack_size",
+        name: "ItemMaxStackSizeComponent",
+        kind: ArgumentKind::Int,
+        value: |arg| Some(ItemMaxStackSizeComponent::new(arg.as_i64()?.try_into().ok()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:armor",
+        name: "ItemArmorComponent",
+        kind: ArgumentKind::Int,
+        value: |arg| Some(ItemArmorComponent::new(arg.as_i64()?.try_into().ok()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:bundle_interaction",
+        name: "ItemBundleInteractionComponent",
+        kind: ArgumentKind::Int,
+        value: |arg| {
+            Some(ItemBundleInteractionComponent::new(arg.as_i64()?.try_into().ok()?).value())
+        },
+    },
+    TypedComponent {
+        id: "minecraft:can_destroy_in_creative",
+        name: "ItemCanDestroyInCreativeComponent",
+        kind: ArgumentKind::Bool,
+        value: |arg| Some(ItemCanDestroyInCreativeComponent::new(arg.as_bool()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:glint",
+        name: "ItemGlintComponent",
+        kind: ArgumentKind::Bool,
+        value: |arg| Some(ItemGlintComponent::new(arg.as_bool()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:liquid_clipped",
+        name: "ItemLiquidClippedComponent",
+        kind: ArgumentKind::Bool,
+        value: |arg| Some(ItemLiquidClippedComponent::new(arg.as_bool()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:stacked_by_data",
+        name: "ItemStackedByDataComponent",
+        kind: ArgumentKind::Bool,
+        value: |arg| Some(ItemStackedByDataComponent::new(arg.as_bool()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:should_despawn",
+        name: "ItemShouldDespawnComponent",
+        kind: ArgumentKind::Bool,
+        value: |arg| Some(ItemShouldDespawnComponent::new(arg.as_bool()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:compostable",
+        name: "ItemCompostableComponent",
+        kind: ArgumentKind::Int,
+        value: |arg| Some(ItemCompostableComponent::new(arg.as_i64()?.try_into().ok()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:swing_duration",
+        name: "ItemSwingDurationComponent",
+        kind: ArgumentKind::Float,
+        value: |arg| Some(ItemSwingDurationComponent::new(arg.as_f64()?).value()),
+    },
+];
+
+const BLOCK_COMPONENTS: &[TypedComponent] = &[
+    TypedComponent {
+        id: "minecraft:display_name",
+        name: "BlockDisplayNameComponent",
+        kind: ArgumentKind::Str,
+        value: |arg| Some(BlockDisplayNameComponent::new(arg.as_str()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:destructible_by_explosion",
+        name: "BlockDestructibleByExplosionComponent",
+        kind: ArgumentKind::Float,
+        value: |arg| Some(BlockDestructibleByExplosionComponent::new(arg.as_f64()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:destructible_by_mining",
+        name: "BlockDestructibleByMiningComponent",
+        kind: ArgumentKind::Float,
+        value: |arg| Some(BlockDestructibleByMiningComponent::new(arg.as_f64()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:friction",
+        name: "BlockFrictionComponent",
+        kind: ArgumentKind::Float,
+        value: |arg| Some(BlockFrictionComponent::new(arg.as_f64()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:light_dampening",
+        name: "BlockLightDampeningComponent",
+        kind: ArgumentKind::Int,
+        value: |arg| {
+            Some(BlockLightDampeningComponent::new(arg.as_i64()?.try_into().ok()?).value())
+        },
+    },
+    TypedComponent {
+        id: "minecraft:light_emission",
+        name: "BlockLightEmissionComponent",
+        kind: ArgumentKind::Int,
+        value: |arg| Some(BlockLightEmissionComponent::new(arg.as_i64()?.try_into().ok()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:loot",
+        name: "BlockLootComponent",
+        kind: ArgumentKind::Str,
+        value: |arg| Some(BlockLootComponent::new(arg.as_str()?).value()),
+    },
+    TypedComponent {
+        id: "minecraft:entity_fall_on",
+        name: "BlockEntityFallOnComponent",
+        kind: ArgumentKind::Float,
+        value: |arg| Some(BlockEntityFallOnComponent::new(arg.as_f64()?).value()),
+    },
+];
+
+/// Collects the statements of the generated `register` function and the
+/// `use` declarations they need.
+struct SourceWriter {
+    uses: BTreeSet<String>,
+    body: Vec<String>,
+}
+
+impl SourceWriter {
+    fn new() -> Self {
+        Self {
+            uses: BTreeSet::new(),
+            body: vec![],
+        }
+    }
+
+    fn use_path(&mut self, path: &str) {
+        self.uses.insert(path.to_string());
+    }
+
+    fn statement(&mut self, statement: String) {
+        self.body.push(statement);
+    }
+
+    fn comment(&mut self, comment: String) {
+        self.body.push(format!("// {}", comment));
+    }
+
+    fn finish(self, bp_dir: &Path, rp_dir: &Path) -> String {
+        let mut source = format!(
+            "//! Generated by violin_rs from {:?} and {:?}.\n\n",
+            bp_dir.to_string_lossy(),
+            rp_dir.to_string_lossy()
+        );
+
+        for path in &self.uses {
+            source.push_str(&format!("use {};\n", path));
+        }
+
+        source.push_str("\npub fn register(pack: &mut Pack) {\n");
+        for (index, statement) in self.body.iter().enumerate() {
+            if index > 0 && !statement.starts_with("//") {
+                source.push('\n');
+            }
+            for line in statement.lines() {
+                source.push_str(&format!("    {}\n", line));
+            }
+        }
+        source.push_str("}\n");
+
+        source
+    }
+}
+
+/// Reads an existing behavior and resource pack and generates a Rust module
+/// with a `pub fn register(pack: &mut Pack)` registering its items, blocks,
+/// recipes and textures through Violin's builders.
+///
+/// Components with a single value are written with their typed struct, every
+/// other component as a raw component holding its JSON. Everything that
+/// can't be expressed is left as a comment in the generated code.
+pub fn generate_pack_source(
+    bp_dir: impl Into<PathBuf>,
+    rp_dir: impl Into<PathBuf>,
+) -> Result<String, ParseError> {
+    let bp_dir: PathBuf = bp_dir.into();
+    let rp_dir: PathBuf = rp_dir.into();
+
+    // Both folders have to be packs, same as in `Pack::import`.
+    read_json(&bp_dir.join("manifest.json"))?;
+    read_json(&rp_dir.join("manifest.json"))?;
+
+    let mut writer = SourceWriter::new();
+    writer.use_path("violin_rs::pack::Pack");
+
+    write_item_textures(&mut writer, &rp_dir);
+    write_block_textures(&mut writer, &rp_dir);
+
+    for file in pack_files(&bp_dir) {
+        if !file.ends_with(".json") {
+            continue;
+        }
+
+        let result = fs::read_to_string(bp_dir.join(&file))
+            .map_err(ParseError::from)
+            .and_then(|src| Ok(serde_json::from_str::<Value>(&src)?))
+            .and_then(|value| {
+                if file.starts_with("items/") {
+                    write_item(&mut writer, &value, &Item::from_value(&value)?);
+                } else if file.starts_with("blocks/") {
+                    write_block(&mut writer, &value, &Block::from_value(&value)?);
+                } else if file.starts_with("recipes/") {
+                    write_recipe(&mut writer, &value)?;
+                }
+                Ok(())
+            });
+
+        if let Err(err) = result {
+            writer.comment(format!("Cannot generate code for \"{}\": {}", file, err));
+        }
+    }
+
+    Ok(writer.finish(&bp_dir, &rp_dir))
+}
+
+fn write_item_textures(writer: &mut SourceWriter, rp_dir: &Path) {
+    let Ok(atlas) = read_json(&rp_dir.join("textures/item_texture.json")) else {
+        return;
+    };
+    let (entries, skipped) = read_texture_data(&atlas);
+
+    for (key, texture_path) in entries {
+        let file_name = texture_path.strip_prefix("textures/items/");
+
+        match file_name.zip(find_texture(rp_dir, &texture_path)) {
+            Some((file_name, (file, _))) => {
+                writer.use_path("violin_rs::image::Image");
+                writer.use_path("violin_rs::item::item_registry::ItemTexture");
+                writer.statement(format!(
+                    "pack.register_item_texture(ItemTexture::new(\n    {:?},\n    {:?},\n    Image::new({:?}),\n));",
+                    key,
+                    file_name,
+                    rp_dir.join(file).to_string_lossy()
+                ));
+            }
+            None => writer.comment(format!(
+                "Item texture \"{}\" isn't in textures/items and has to be registered manually",
+                key
+            )),
+        }
+    }
+    for key in skipped {
+        writer.comment(format!(
+            "Item texture \"{}\" has no single texture path and has to be registered manually",
+            key
+        ));
+    }
+}
+
+fn write_block_textures(writer: &mut SourceWriter, rp_dir: &Path) {
+    if let Ok(atlas) = read_json(&rp_dir.join("textures/terrain_texture.json")) {
+        let (entries, skipped) = read_texture_data(&atlas);
+
+        for (key, texture_path) in entries {
+            // `register_block_texture` keys the terrain atlas by the texture's
+            // identifier and always points to a `.png` in textures/blocks.
+            let texture = Identifier::parse(&key)
+                .zip(texture_path.strip_prefix("textures/blocks/"))
+                .zip(find_texture(rp_dir, &texture_path))
+                .filter(|(_, (file, _))| file.ends_with(".png"));
+
+            match texture {
+                Some(((id, file_name), (file, _))) => {
+                    writer.use_path("violin_rs::block::block_registry::BlockTexture");
+                    writer.use_path("violin_rs::image::Image");
+                    writer.use_path("violin_rs::vio::Identifier");
+                    writer.statement(format!(
+                        "pack.register_block_texture(BlockTexture::new(\n    Image::new({:?}),\n    {},\n    {:?},\n));",
+                        rp_dir.join(file).to_string_lossy(),
+                        identifier_source(&id),
+                        file_name
+                    ));
+                }
+                None => writer.comment(format!(
+                    "Terrain texture \"{}\" ({}) has to be registered manually",
+                    key, texture_path
+                )),
+            }
+        }
+        for key in skipped {
+            writer.comment(format!(
+                "Terrain texture \"{}\" has no single texture path and has to be registered manually",
+                key
+            ));
+        }
+    }
+
+    if let Ok(atlas) = read_json(&rp_dir.join("blocks.json")) {
+        for (key, entry) in atlas.as_object().into_iter().flatten() {
+            if key == "format_version" {
+                continue;
+            }
+
+            let id =
+                Identifier::parse(key).filter(|_| read_block_atlas_entry(key, entry).is_some());
+            let Some(id) = id else {
+                writer.comment(format!(
+                    "Block atlas entry \"{}\" has to be registered manually",
+                    key
+                ));
+                continue;
+            };
+            let sound = entry["sound"].as_str().unwrap_or_default();
+
+            writer.use_path("violin_rs::vio::Buildable");
+            writer.use_path("violin_rs::vio::Identifier");
+            match &entry["textures"] {
+                Value::String(textures) => {
+                    writer.use_path("violin_rs::block::block_registry::AllBlockAtlasEntry");
+                    writer.statement(format!(
+                        "pack.register_block_atlas_entry(\n    AllBlockAtlasEntry::new({}, {:?}, {:?}).build(),\n);",
+                        identifier_source(&id),
+                        textures,
+                        sound
+                    ));
+                }
+                faces => {
+                    writer.use_path("violin_rs::block::block_registry::Faces");
+                    writer.use_path("violin_rs::block::block_registry::PerFaceBlockAtlasEntry");
+                    let face = |name: &str| {
+                        let texture = faces.get(name).or_else(|| match name {
+                            "north" | "south" | "east" | "west" => faces.get("side"),
+                            _ => None,
+                        });
+                        format!("{:?}", texture.and_then(Value::as_str).unwrap_or_default())
+                    };
+                    writer.statement(format!(
+                        "pack.register_block_atlas_entry(\n    PerFaceBlockAtlasEntry::new(\n        {},\n        Faces::new(\n            {},\n            {},\n            {},\n            {},\n            {},\n            {},\n        ),\n        {:?},\n    )\n    .build(),\n);",
+                        identifier_source(&id),
+                        face("up"),
+                        face("down"),
+                        face("north"),
+                        face("south"),
+                        face("east"),
+                        face("west"),
+                        sound
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn write_item(writer: &mut SourceWriter, value: &Value, item: &Item) {
+    writer.use_path("violin_rs::item::Item");
+    writer.use_path("violin_rs::vio::Identifier");
+    writer.use_path("violin_rs::vio::SemVer");
+
+    let mut source = format!(
+        "pack.register_item(\n    Item::new({})\n        .using_format_version({})",
+        identifier_source(&item.type_id),
+        semver_source(&item.format_version)
+    );
+    if let Some(menu_category) = &item.menu_category {
+        source.push_str(&format!(
+            "\n        .using_menu_category({})",
+            menu_category_source(writer, menu_category)
+        ));
+    }
+
+    let components = components_source(
+        writer,
+        &value["minecraft:item"]["components"],
+        ITEM_COMPONENTS,
+        "violin_rs::item::component",
+        "ItemRawComponent",
+    );
+    source.push_str(&format!(
+        "\n        .using_components({}),\n);",
+        list_source(&components, 2)
+    ));
+
+    writer.statement(source);
+}
+
+fn write_block(writer: &mut SourceWriter, value: &Value, block: &Block) {
+    writer.use_path("violin_rs::block::Block");
+    writer.use_path("violin_rs::vio::Identifier");
+    writer.use_path("violin_rs::vio::SemVer");
+
+    let mut source = format!(
+        "pack.register_block(\n    Block::new({})\n        .using_format_version({})",
+        identifier_source(&block.type_id()),
+        semver_source(&block.format_version)
+    );
+    if let Some(menu_category) = &block.menu_category {
+        source.push_str(&format!(
+            "\n        .using_menu_category({})",
+            menu_category_source(writer, menu_category)
+        ));
+    }
+
+    if !block.states.is_empty() {
+        writer.use_path("violin_rs::vio::Buildable");
+        let states: Vec<String> = block
+            .states
+            .iter()
+            .map(|state| state_source(writer, &state.id(), &state.value()))
+            .collect();
+        source.push_str(&format!(
+            "\n        .using_states({})",
+            list_source(&states, 2)
+        ));
+    }
+
+    if let Some(traits) = value["minecraft:block"]["description"]["traits"].as_object() {
+        if !traits.is_empty() {
+            let traits: Vec<String> = traits
+                .iter()
+                .map(|(id, value)| trait_source(writer, id, value))
+                .collect();
+            source.push_str(&format!(
+                "\n        .using_traits({})",
+                list_source(&traits, 2)
+            ));
+        }
+    }
+
+    let components = block_components_source(writer, &value["minecraft:block"]["components"]);
+    source.push_str(&format!(
+        "\n        .using_components({})",
+        list_source(&components, 2)
+    ));
+
+    if !block.permutations.is_empty() {
+        writer.use_path("violin_rs::block::permutation::BlockPermutation");
+        let permutations: Vec<String> = block
+            .permutations
+            .iter()
+            .zip(
+                value["minecraft:block"]["permutations"]
+                    .as_array()
+                    .into_iter()
+                    .flatten(),
+            )
+            .map(|(permutation, raw_permutation)| {
+                let components = block_components_source(writer, &raw_permutation["components"]);
+                format!(
+                    "BlockPermutation::new(\n    {:?},\n    {},\n)",
+                    permutation.condition,
+                    list_source(&components, 1)
+                )
+            })
+            .collect();
+        source.push_str(&format!(
+            "\n        .using_permutations({})",
+            list_source(&permutations, 2)
+        ));
+    }
+
+    source.push_str(",\n);");
+    writer.statement(source);
+}
+
+fn block_components_source(writer: &mut SourceWriter, components: &Value) -> Vec<String> {
+    components_source(
+        writer,
+        components,
+        BLOCK_COMPONENTS,
+        "violin_rs::block::component",
+        "BlockRawComponent",
+    )
+}
+
+/// The code building each component of a `components` object. The original
+/// JSON is used rather than the imported components, so raw components keep
+/// exactly the fields that were written.
+fn components_source(
+    writer: &mut SourceWriter,
+    components: &Value,
+    typed_components: &[TypedComponent],
+    module: &str,
+    raw_name: &str,
+) -> Vec<String> {
+    components
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(id, value)| component_source(writer, id, value, typed_components, module, raw_name))
+        .collect()
+}
+
+fn write_recipe(writer: &mut SourceWriter, value: &Value) -> Result<(), ParseError> {
+    writer.use_path("violin_rs::vio::Buildable");
+    writer.use_path("violin_rs::vio::Identifier");
+    writer.use_path("violin_rs::recipe::RecipeIO");
+
+    let source = if value.get("minecraft:recipe_furnace").is_some() {
+        let recipe = FurnaceRecipe::from_value(value)?;
+        writer.use_path("violin_rs::recipe::FurnaceRecipe");
+
+        format!(
+            "FurnaceRecipe::new(\n    {},\n    {},\n    {},\n){}",
+            identifier_source(&recipe.id),
+            recipe_io_source(&recipe.input),
+            identifier_source(&recipe.output),
+            tags_source(&recipe.tags)
+        )
+    } else if value.get("minecraft:recipe_shapeless").is_some() {
+        let recipe = ShapelessRecipe::from_value(value)?;
+        writer.use_path("violin_rs::recipe::ShapelessRecipe");
+
+        format!(
+            "ShapelessRecipe::new(\n    {},\n    {},\n){}\n.using_ingredients({})",
+            identifier_source(&recipe.id),
+            recipe_io_source(&recipe.result),
+            tags_source(&recipe.tags),
+            list_source(
+                &recipe
+                    .ingredients
+                    .iter()
+                    .map(recipe_io_source)
+                    .collect::<Vec<String>>(),
+                0
+            )
+        )
+    } else if value.get("minecraft:recipe_shaped").is_some() {
+        let recipe = ShapedRecipe::from_value(value)?;
+        writer.use_path("violin_rs::recipe::ShapedRecipe");
+
+        format!(
+            "ShapedRecipe::new(\n    {},\n    {},\n){}\n.using_pattern(vec!{:?})\n.using_ingredients({})",
+            identifier_source(&recipe.id),
+            recipe_io_source(&recipe.result),
+            tags_source(&recipe.tags),
+            recipe.pattern,
+            list_source(
+                &recipe.ingredients.iter().map(recipe_io_source).collect::<Vec<String>>(),
+                0
+            )
+        )
+    } else {
+        return Err(ParseError::MissingField(
+            "minecraft:recipe_furnace | minecraft:recipe_shapeless | minecraft:recipe_shaped"
+                .to_string(),
+        ));
+    };
+
+    writer.statement(format!(
+        "pack.register_recipe(\n{}\n    .build(),\n);",
+        indent(&source, 1)
+    ));
+
+    Ok(())
+}
+
+/// The code building a component, e.g. `ItemDamageComponent::new(5).build()`.
+fn component_source(
+    writer: &mut SourceWriter,
+    id: &str,
+    value: &Value,
+    typed_components: &[TypedComponent],
+    module: &str,
+    raw_name: &str,
+) -> String {
+    writer.use_path("violin_rs::vio::Buildable");
+
+    let typed = typed_components
+        .iter()
+        .find(|typed| typed.id == id)
+        .and_then(|typed| {
+            let arg = match value {
+                Value::Object(fields) if fields.len() == 1 => fields.values().next()?,
+                value => value,
+            };
+            let built = (typed.value)(arg)?;
+
+            json_equivalent(&built, value).then(|| (typed.name, argument_source(&typed.kind, arg)))
+        });
+
+    match typed {
+        Some((name, Some(arg))) => {
+            writer.use_path(&format!("{}::{}", module, name));
+            format!("{}::new({}).build()", name, arg)
+        }
+        _ => {
+            writer.use_path(&format!("{}::{}", module, raw_name));
+            writer.use_path("serde_json::json");
+            format!(
+                "{}::new(\n    {:?},\n    json!({}),\n)\n.build()",
+                raw_name,
+                id,
+                indent(&json_source(value), 1).trim_start()
+            )
+        }
+    }
+}
+
+/// The code building a block trait. Traits the typed structs represent
+/// without loss are written with them, others as a `BlockRawTrait`.
+fn trait_source(writer: &mut SourceWriter, id: &str, value: &Value) -> String {
+    let typed = match id {
+        "minecraft:placement_direction" => {
+            serde_json::from_value::<PlacementDirectionTrait>(value.clone())
+                .ok()
+                .filter(|block_trait| json_equivalent(&block_trait.value(), value))
+                .map(|block_trait| {
+                    writer.use_path("violin_rs::block::traits::PlacementDirectionState");
+                    writer.use_path("violin_rs::block::traits::PlacementDirectionTrait");
+                    let states: Vec<String> = block_trait
+                        .enabled_states
+                        .iter()
+                        .map(|state| format!("PlacementDirectionState::{:?}", state))
+                        .collect();
+                    let mut source =
+                        format!("PlacementDirectionTrait::new(vec![{}])", states.join(", "));
+                    if let Some(offset) = block_trait.y_rotation_offset {
+                        source.push_str(&format!(".using_y_rotation_offset({:?})", offset));
+                    }
+                    source
+                })
+        }
+        "minecraft:placement_position" => {
+            serde_json::from_value::<PlacementPositionTrait>(value.clone())
+                .ok()
+                .filter(|block_trait| json_equivalent(&block_trait.value(), value))
+                .map(|block_trait| {
+                    writer.use_path("violin_rs::block::traits::PlacementPositionState");
+                    writer.use_path("violin_rs::block::traits::PlacementPositionTrait");
+                    let states: Vec<String> = block_trait
+                        .enabled_states
+                        .iter()
+                        .map(|state| format!("PlacementPositionState::{:?}", state))
+                        .collect();
+                    format!("PlacementPositionTrait::new(vec![{}])", states.join(", "))
+                })
+        }
+        "minecraft:connection" => serde_json::from_value::<ConnectionTrait>(value.clone())
+            .ok()
+            .filter(|block_trait| json_equivalent(&block_trait.value(), value))
+            .map(|block_trait| {
+                writer.use_path("violin_rs::block::traits::ConnectionState");
+                writer.use_path("violin_rs::block::traits::ConnectionTrait");
+                let states: Vec<String> = block_trait
+                    .enabled_states
+                    .iter()
+                    .map(|state| format!("ConnectionState::{:?}", state))
+                    .collect();
+                format!("ConnectionTrait::new(vec![{}])", states.join(", "))
+            }),
+        _ => None,
+    };
+
+    match typed {
+        Some(source) => {
+            writer.use_path("violin_rs::vio::Buildable");
+            format!("{}.build()", source)
+        }
+        None => component_source(
+            writer,
+            id,
+            value,
+            &[],
+            "violin_rs::block::traits",
+            "BlockRawTrait",
+        ),
+    }
+}
+
+fn argument_source(kind: &ArgumentKind, arg: &Value) -> Option<String> {
+    match kind {
+        ArgumentKind::Bool => arg.as_bool().map(|arg| arg.to_string()),
+        ArgumentKind::Int => arg.as_i64().map(|arg| arg.to_string()),
+        ArgumentKind::Float => arg.as_f64().map(|arg| format!("{:?}", arg)),
+        ArgumentKind::Str => arg.as_str().map(|arg| format!("{:?}", arg)),
+    }
+}
+
+fn state_source(writer: &mut SourceWriter, id: &Identifier, value: &Value) -> String {
+    let id = identifier_source(id);
+
+    if let Some(range) = value.get("values") {
+        writer.use_path("violin_rs::block::state::RangedBlockState");
+        return format!(
+            "RangedBlockState::new({}, {}, {}).build()",
+            id, range["min"], range["max"]
+        );
+    }
+
+    let values = value.as_array().cloned().unwrap_or_default();
+    if values.iter().all(Value::is_boolean) {
+        writer.use_path("violin_rs::block::state::BoolBlockState");
+        format!("BoolBlockState {{ id: {} }}.build()", id)
+    } else if values.iter().all(Value::is_i64) {
+        writer.use_path("violin_rs::block::state::NumericBlockState");
+        format!(
+            "NumericBlockState::new({}, vec!{}).build()",
+            id,
+            Value::Array(values)
+        )
+    } else {
+        writer.use_path("violin_rs::block::state::StringBlockState");
+        let values: Vec<&str> = values.iter().filter_map(Value::as_str).collect();
+        format!("StringBlockState::new({}, vec!{:?}).build()", id, values)
+    }
+}
+
+fn menu_category_source(writer: &mut SourceWriter, menu_category: &MenuCategory) -> String {
+    writer.use_path("violin_rs::item::utils::MenuCategory");
+    writer.use_path("violin_rs::item::utils::MenuCategoryName");
+
+    let mut source = format!(
+        "MenuCategory::new(MenuCategoryName::{:?})",
+        menu_category.category
+    );
+    if let Some(group) = &menu_category.group {
+        source.push_str(&format!(".using_group({:?})", group));
+    }
+    if let Some(is_hidden_in_commands) = menu_category.is_hidden_in_commands {
+        source.push_str(&format!(
+            ".using_is_hidden_in_commands({})",
+            is_hidden_in_commands
+        ));
+    }
+
+    source
+}
+
+fn recipe_io_source(io: &RecipeIO) -> String {
+    let mut source = match (&io.item, &io.tag) {
+        (Some(item), _) if !io.use_tag => {
+            format!("RecipeIO::new_typed({})", identifier_source(item))
+        }
+        (_, tag) => format!(
+            "RecipeIO::new_tagged({:?})",
+            tag.clone().unwrap_or_default()
+        ),
+    };
+
+    // `new_typed` already sets the data to 0.
+    if let Some(data) = io.data.filter(|data| io.use_tag || *data != 0) {
+        source.push_str(&format!(".using_data({})", data));
+    }
+    if let Some(count) = io.count {
+        source.push_str(&format!(".using_count({})", count));
+    }
+    if let Some(key) = io.key {
+        source.push_str(&format!(".using_key({:?})", key));
+    }
+
+    source
+}
+
+fn tags_source(tags: &[String]) -> String {
+    if tags.is_empty() {
+        String::new()
+    } else {
+        format!("\n.using_tags(vec!{:?})", tags)
+    }
+}
+
+fn identifier_source(id: &Identifier) -> String {
+    format!("Identifier::new({:?}, {:?})", id.namespace, id.value)
+}
+
+fn semver_source(version: &SemVer) -> String {
+    let rendered = version.render();
+    let (version, constructor) = match rendered.strip_suffix("-beta") {
+        Some(version) => (version, "new_beta"),
+        None => (rendered.as_str(), "new"),
+    };
+
+    format!("SemVer::{}({})", constructor, version.replace('.', ", "))
+}
+
+/// A `vec![...]` of the given expressions, one per line.
+fn list_source(entries: &[String], level: usize) -> String {
+    if entries.is_empty() {
+        return "vec![]".to_string();
+    }
+
+    let entries: Vec<String> = entries
+        .iter()
+        .map(|entry| format!("{},", indent(entry, level + 1)))
+        .collect();
+
+    format!("vec![\n{}\n{}]", entries.join("\n"), "    ".repeat(level))
+}
+
+fn indent(src: &str, level: usize) -> String {
+    let prefix = "    ".repeat(level);
+
+    src.lines()
+        .map(|line| format!("{}{}", prefix, line))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// The value written as the contents of a `json!` invocation. Strings are
+/// written as Rust string literals, so any character is escaped correctly.
+fn json_source(value: &Value) -> String {
+    match value {
+        Value::String(string) => format!("{:?}", string),
+        Value::Array(values) if values.is_empty() => "[]".to_string(),
+        Value::Array(values) => {
+            let values: Vec<String> = values
+                .iter()
+                .map(|value| indent(&json_source(value), 1))
+                .collect();
+
+            format!("[\n{}\n]", values.join(",\n"))
+        }
+        Value::Object(fields) if fields.is_empty() => "{}".to_string(),
+        Value::Object(fields) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(key, value)| indent(&format!("{:?}: {}", key, json_source(value)), 1))
+                .collect();
+
+            format!("{{\n{}\n}}", fields.join(",\n"))
+        }
+        value => value.to_string(),
+    }
+}
@@ -0,0 +1,664 @@
+use crate::block::Block;
+use crate::vio::{Buildable, Identifier, MolangStatement, RangeDescriptor, SemVer};
+use derive_setters::Setters;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashMap;
+
+pub mod rule;
+
+/// A world generation feature, written to `BP/features`.
+///
+/// The file name of a feature has to match the value of its identifier, so
+/// features are generated as `BP/features/<value>.json`.
+pub trait Feature {
+    fn serialize(&self) -> String;
+    fn id(&self) -> Identifier;
+}
+
+fn serialize_feature(
+    feature_type: &str,
+    id: &Identifier,
+    format_version: &SemVer,
+    body: &impl Serialize,
+) -> String {
+    let mut value = serde_json::to_value(body).unwrap();
+    value.as_object_mut().unwrap().insert(
+        "description".to_string(),
+        json!({ "identifier": id.render() }),
+    );
+
+    json!({
+        "format_version": format_version.render(),
+        feature_type: value
+    })
+    .to_string()
+}
+
+fn feature_format_version() -> SemVer {
+    SemVer::new(1, 13, 0)
+}
+
+// * OreFeature
+
+#[derive(Clone, Debug, Serialize)]
+pub struct OreReplaceRule {
+    pub places_block: Identifier,
+    pub may_replace: Vec<Identifier>,
+}
+
+impl OreReplaceRule {
+    pub fn new(places_block: Identifier, may_replace: Vec<Identifier>) -> Self {
+        Self {
+            places_block,
+            may_replace,
+        }
+    }
+
+    /// Places the given custom block in place of the `may_replace` blocks.
+    pub fn for_block(block: &Block, may_replace: Vec<Identifier>) -> Self {
+        Self::new(block.type_id(), may_replace)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct OreFeature {
+    #[serde(skip)]
+    #[setters(skip)]
+    pub id: Identifier,
+    #[serde(skip)]
+    pub format_version: SemVer,
+    pub count: i32,
+    pub replace_rules: Vec<OreReplaceRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub discard_chance_on_air_exposure: Option<f64>,
+}
+
+impl OreFeature {
+    pub fn new(id: Identifier, count: i32, replace_rules: Vec<OreReplaceRule>) -> Self {
+        Self {
+            id,
+            format_version: feature_format_version(),
+            count,
+            replace_rules,
+            discard_chance_on_air_exposure: None,
+        }
+    }
+}
+
+impl Feature for OreFeature {
+    fn serialize(&self) -> String {
+        serialize_feature("minecraft:ore_feature", &self.id, &self.format_version, self)
+    }
+
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+}
+
+impl Buildable for OreFeature {}
+
+// * ScatterFeature
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoordinateDistribution {
+    Uniform,
+    Gaussian,
+    InverseGaussian,
+    FixedGrid,
+    JitteredGrid,
+    Triangle,
+}
+
+/// A coordinate range used by scatter features and feature rule distributions.
+#[derive(Clone, Debug, Serialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct CoordinateRange {
+    #[setters(skip)]
+    pub distribution: CoordinateDistribution,
+    #[setters(skip)]
+    pub extent: [MolangStatement; 2],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub step_size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub grid_offset: Option<i32>,
+}
+
+impl CoordinateRange {
+    pub fn new(
+        distribution: CoordinateDistribution,
+        min: impl Into<String>,
+        max: impl Into<String>,
+    ) -> Self {
+        Self {
+            distribution,
+            extent: [MolangStatement::new(min), MolangStatement::new(max)],
+            step_size: None,
+            grid_offset: None,
+        }
+    }
+
+    pub fn uniform(min: impl Into<String>, max: impl Into<String>) -> Self {
+        Self::new(CoordinateDistribution::Uniform, min, max)
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum FeatureCoordinate {
+    Molang(MolangStatement),
+    Range(CoordinateRange),
+}
+
+impl FeatureCoordinate {
+    pub fn molang(src: impl Into<String>) -> Self {
+        Self::Molang(MolangStatement::new(src))
+    }
+}
+
+impl From<CoordinateRange> for FeatureCoordinate {
+    fn from(value: CoordinateRange) -> Self {
+        Self::Range(value)
+    }
+}
+
+impl From<MolangStatement> for FeatureCoordinate {
+    fn from(value: MolangStatement) -> Self {
+        Self::Molang(value)
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoordinateEvalOrder {
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ScatterChance {
+    pub numerator: i32,
+    pub denominator: i32,
+}
+
+impl ScatterChance {
+    pub fn new(numerator: i32, denominator: i32) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct ScatterFeature {
+    #[serde(skip)]
+    #[setters(skip)]
+    pub id: Identifier,
+    #[serde(skip)]
+    pub format_version: SemVer,
+    pub places_feature: Identifier,
+    pub iterations: MolangStatement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub scatter_chance: Option<ScatterChance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub coordinate_eval_order: Option<CoordinateEvalOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub project_input_to_floor: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option, into)]
+    pub x: Option<FeatureCoordinate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option, into)]
+    pub y: Option<FeatureCoordinate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option, into)]
+    pub z: Option<FeatureCoordinate>,
+}
+
+impl ScatterFeature {
+    pub fn new(id: Identifier, places_feature: Identifier, iterations: impl Into<String>) -> Self {
+        Self {
+            id,
+            format_version: feature_format_version(),
+            places_feature,
+            iterations: MolangStatement::new(iterations),
+            scatter_chance: None,
+            coordinate_eval_order: None,
+            project_input_to_floor: None,
+            x: None,
+            y: None,
+            z: None,
+        }
+    }
+}
+
+impl Feature for ScatterFeature {
+    fn serialize(&self) -> String {
+        serialize_feature(
+            "minecraft:scatter_feature",
+            &self.id,
+            &self.format_version,
+            self,
+        )
+    }
+
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+}
+
+impl Buildable for ScatterFeature {}
+
+// * SingleBlockFeature
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FeatureBlockReference {
+    pub name: Identifier,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub states: HashMap<String, serde_json::Value>,
+}
+
+impl FeatureBlockReference {
+    pub fn new(name: Identifier) -> Self {
+        Self {
+            name,
+            states: HashMap::new(),
+        }
+    }
+
+    pub fn using_state(self, state: Identifier, value: impl Into<serde_json::Value>) -> Self {
+        let mut states = self.states;
+        states.insert(state.render(), value.into());
+
+        Self { states, ..self }
+    }
+}
+
+impl From<Identifier> for FeatureBlockReference {
+    fn from(value: Identifier) -> Self {
+        Self::new(value)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Default, Setters)]
+#[setters(prefix = "using_")]
+pub struct FeatureAttachment {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub top: Vec<Identifier>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub bottom: Vec<Identifier>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub north: Vec<Identifier>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub south: Vec<Identifier>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub east: Vec<Identifier>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub west: Vec<Identifier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub min_sides_must_attach: Option<u8>,
+}
+
+#[derive(Clone, Debug, Serialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct SingleBlockFeature {
+    #[serde(skip)]
+    #[setters(skip)]
+    pub id: Identifier,
+    #[serde(skip)]
+    pub format_version: SemVer,
+    pub places_block: FeatureBlockReference,
+    pub enforce_placement_rules: bool,
+    pub enforce_survivability_rules: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub may_attach_to: Option<FeatureAttachment>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub may_replace: Vec<Identifier>,
+}
+
+impl SingleBlockFeature {
+    pub fn new(id: Identifier, places_block: impl Into<FeatureBlockReference>) -> Self {
+        Self {
+            id,
+            format_version: feature_format_version(),
+            places_block: places_block.into(),
+            enforce_placement_rules: false,
+            enforce_survivability_rules: false,
+            may_attach_to: None,
+            may_replace: vec![],
+        }
+    }
+}
+
+impl Feature for SingleBlockFeature {
+    fn serialize(&self) -> String {
+        serialize_feature(
+            "minecraft:single_block_feature",
+            &self.id,
+            &self.format_version,
+            self,
+        )
+    }
+
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+}
+
+impl Buildable for SingleBlockFeature {}
+
+// * StructureTemplateFeature
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FacingDirection {
+    North,
+    South,
+    East,
+    West,
+    Random,
+}
+
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct StructureConstraints {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grounded: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unburied: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_intersection: Option<StructureBlockIntersection>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct StructureBlockIntersection {
+    pub block_allowlist: Vec<Identifier>,
+}
+
+impl StructureConstraints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn grounded(self) -> Self {
+        Self {
+            grounded: Some(HashMap::new()),
+            ..self
+        }
+    }
+
+    pub fn unburied(self) -> Self {
+        Self {
+            unburied: Some(HashMap::new()),
+            ..self
+        }
+    }
+
+    pub fn using_block_allowlist(self, block_allowlist: Vec<Identifier>) -> Self {
+        Self {
+            block_intersection: Some(StructureBlockIntersection { block_allowlist }),
+            ..self
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct StructureTemplateFeature {
+    #[serde(skip)]
+    #[setters(skip)]
+    pub id: Identifier,
+    #[serde(skip)]
+    pub format_version: SemVer,
+    pub structure_name: Identifier,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub adjustment_radius: Option<u8>,
+    pub facing_direction: FacingDirection,
+    pub constraints: StructureConstraints,
+}
+
+impl StructureTemplateFeature {
+    pub fn new(id: Identifier, structure_name: Identifier) -> Self {
+        Self {
+            id,
+            format_version: feature_format_version(),
+            structure_name,
+            adjustment_radius: None,
+            facing_direction: FacingDirection::Random,
+            constraints: StructureConstraints::new().grounded(),
+        }
+    }
+}
+
+impl Feature for StructureTemplateFeature {
+    fn serialize(&self) -> String {
+        serialize_feature(
+            "minecraft:structure_template_feature",
+            &self.id,
+            &self.format_version,
+            self,
+        )
+    }
+
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+}
+
+impl Buildable for StructureTemplateFeature {}
+
+// * AggregateFeature
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregateEarlyOut {
+    None,
+    FirstFailure,
+    FirstSuccess,
+}
+
+#[derive(Clone, Debug, Serialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct AggregateFeature {
+    #[serde(skip)]
+    #[setters(skip)]
+    pub id: Identifier,
+    #[serde(skip)]
+    pub format_version: SemVer,
+    pub features: Vec<Identifier>,
+    pub early_out: AggregateEarlyOut,
+}
+
+impl AggregateFeature {
+    pub fn new(id: Identifier, features: Vec<Identifier>) -> Self {
+        Self {
+            id,
+            format_version: feature_format_version(),
+            features,
+            early_out: AggregateEarlyOut::None,
+        }
+    }
+}
+
+impl Feature for AggregateFeature {
+    fn serialize(&self) -> String {
+        serialize_feature(
+            "minecraft:aggregate_feature",
+            &self.id,
+            &self.format_version,
+            self,
+        )
+    }
+
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+}
+
+impl Buildable for AggregateFeature {}
+
+// * SequenceFeature
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SequenceFeature {
+    #[serde(skip)]
+    pub id: Identifier,
+    #[serde(skip)]
+    pub format_version: SemVer,
+    pub features: Vec<Identifier>,
+}
+
+impl SequenceFeature {
+    pub fn new(id: Identifier, features: Vec<Identifier>) -> Self {
+        Self {
+            id,
+            format_version: feature_format_version(),
+            features,
+        }
+    }
+}
+
+impl Feature for SequenceFeature {
+    fn serialize(&self) -> String {
+        serialize_feature(
+            "minecraft:sequence_feature",
+            &self.id,
+            &self.format_version,
+            self,
+        )
+    }
+
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+}
+
+impl Buildable for SequenceFeature {}
+
+// * TreeFeature
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TreeHeight {
+    pub base: i32,
+    pub intervals: Vec<i32>,
+}
+
+impl TreeHeight {
+    pub fn new(base: i32, intervals: Vec<i32>) -> Self {
+        Self { base, intervals }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TreeTrunk {
+    pub trunk_height: TreeHeight,
+    pub trunk_block: FeatureBlockReference,
+}
+
+impl TreeTrunk {
+    pub fn new(trunk_height: TreeHeight, trunk_block: impl Into<FeatureBlockReference>) -> Self {
+        Self {
+            trunk_height,
+            trunk_block: trunk_block.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TreeCanopySlope {
+    pub rise: i32,
+    pub run: i32,
+}
+
+#[derive(Clone, Debug, Serialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct TreeCanopy {
+    #[setters(skip)]
+    pub canopy_offset: RangeDescriptor<i32>,
+    #[setters(skip)]
+    pub leaf_block: FeatureBlockReference,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub min_width: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub canopy_slope: Option<TreeCanopySlope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub variation_chance: Option<ScatterChance>,
+}
+
+impl TreeCanopy {
+    pub fn new(min_offset: i32, max_offset: i32, leaf_block: impl Into<FeatureBlockReference>) -> Self {
+        Self {
+            canopy_offset: RangeDescriptor::new(min_offset, max_offset),
+            leaf_block: leaf_block.into(),
+            min_width: None,
+            canopy_slope: None,
+            variation_chance: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct TreeFeature {
+    #[serde(skip)]
+    #[setters(skip)]
+    pub id: Identifier,
+    #[serde(skip)]
+    pub format_version: SemVer,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub base_block: Vec<Identifier>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub may_grow_on: Vec<Identifier>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub may_replace: Vec<Identifier>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub may_grow_through: Vec<Identifier>,
+    pub trunk: TreeTrunk,
+    pub canopy: TreeCanopy,
+}
+
+impl TreeFeature {
+    pub fn new(id: Identifier, trunk: TreeTrunk, canopy: TreeCanopy) -> Self {
+        Self {
+            id,
+            format_version: feature_format_version(),
+            base_block: vec![],
+            may_grow_on: vec![],
+            may_replace: vec![],
+            may_grow_through: vec![],
+            trunk,
+            canopy,
+        }
+    }
+}
+
+impl Feature for TreeFeature {
+    fn serialize(&self) -> String {
+        serialize_feature("minecraft:tree_feature", &self.id, &self.format_version, self)
+    }
+
+    fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+}
+
+impl Buildable for TreeFeature {}
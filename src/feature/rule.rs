@@ -0,0 +1,157 @@
+use crate::feature::{CoordinateEvalOrder, FeatureCoordinate, ScatterChance};
+use crate::vio::{Identifier, MolangStatement, SemVer};
+use derive_setters::Setters;
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlacementPass {
+    FirstPass,
+    BeforeUndergroundPass,
+    UndergroundPass,
+    AfterUndergroundPass,
+    BeforeSurfacePass,
+    SurfacePass,
+    AfterSurfacePass,
+    BeforeSkyPass,
+    SkyPass,
+    AfterSkyPass,
+    FinalPass,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub enum FilterOperator {
+    #[serde(rename = "==")]
+    Equals,
+    #[serde(rename = "!=")]
+    NotEquals,
+}
+
+/// A single biome filter test, e.g. `has_biome_tag == overworld`.
+#[derive(Clone, Debug, Serialize)]
+pub struct BiomeFilter {
+    pub test: String,
+    pub operator: FilterOperator,
+    pub value: String,
+}
+
+impl BiomeFilter {
+    pub fn new(test: impl Into<String>, operator: FilterOperator, value: impl Into<String>) -> Self {
+        Self {
+            test: test.into(),
+            operator,
+            value: value.into(),
+        }
+    }
+
+    pub fn has_biome_tag(tag: impl Into<String>) -> Self {
+        Self::new("has_biome_tag", FilterOperator::Equals, tag)
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FeatureRuleConditions {
+    pub placement_pass: PlacementPass,
+    #[serde(rename = "minecraft:biome_filter", skip_serializing_if = "Vec::is_empty")]
+    pub biome_filter: Vec<BiomeFilter>,
+}
+
+#[derive(Clone, Debug, Serialize, Setters)]
+#[setters(prefix = "using_")]
+pub struct FeatureDistribution {
+    #[setters(skip)]
+    pub iterations: MolangStatement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub scatter_chance: Option<ScatterChance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option)]
+    pub coordinate_eval_order: Option<CoordinateEvalOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option, into)]
+    pub x: Option<FeatureCoordinate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option, into)]
+    pub y: Option<FeatureCoordinate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option, into)]
+    pub z: Option<FeatureCoordinate>,
+}
+
+impl FeatureDistribution {
+    pub fn new(iterations: impl Into<String>) -> Self {
+        Self {
+            iterations: MolangStatement::new(iterations),
+            scatter_chance: None,
+            coordinate_eval_order: None,
+            x: None,
+            y: None,
+            z: None,
+        }
+    }
+}
+
+/// A feature rule, written to `BP/feature_rules`. Decides where and when the
+/// `places_feature` feature is placed during world generation.
+#[derive(Clone, Debug)]
+pub struct FeatureRule {
+    pub id: Identifier,
+    pub format_version: SemVer,
+    pub places_feature: Identifier,
+    pub conditions: FeatureRuleConditions,
+    pub distribution: FeatureDistribution,
+}
+
+impl FeatureRule {
+    pub fn new(
+        id: Identifier,
+        places_feature: Identifier,
+        placement_pass: PlacementPass,
+        distribution: FeatureDistribution,
+    ) -> Self {
+        Self {
+            id,
+            format_version: SemVer::new(1, 13, 0),
+            places_feature,
+            conditions: FeatureRuleConditions {
+                placement_pass,
+                biome_filter: vec![],
+            },
+            distribution,
+        }
+    }
+
+    pub fn using_biome_filter(&self, biome_filter: Vec<BiomeFilter>) -> Self {
+        let mut sc = self.clone();
+        sc.conditions.biome_filter = biome_filter;
+
+        sc
+    }
+
+    pub fn using_format_version(&self, format_version: SemVer) -> Self {
+        let mut sc = self.clone();
+        sc.format_version = format_version;
+
+        sc
+    }
+
+    pub fn serialize(&self) -> String {
+        json!({
+            "format_version": self.format_version.render(),
+            "minecraft:feature_rules": {
+                "description": {
+                    "identifier": self.id.render(),
+                    "places_feature": self.places_feature.render()
+                },
+                "conditions": self.conditions,
+                "distribution": self.distribution
+            }
+        })
+        .to_string()
+    }
+
+    pub fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+}
@@ -0,0 +1,216 @@
+use crate::vio::{Identifier, RGBColor, SemVer};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FogMedium {
+    Air,
+    Water,
+    Weather,
+    Lava,
+    LavaResistance,
+    PowderSnow,
+    Cloud,
+}
+
+impl FogMedium {
+    pub fn str_medium(&self) -> &str {
+        match self {
+            FogMedium::Air => "air",
+            FogMedium::Water => "water",
+            FogMedium::Weather => "weather",
+            FogMedium::Lava => "lava",
+            FogMedium::LavaResistance => "lava_resistance",
+            FogMedium::PowderSnow => "powder_snow",
+            FogMedium::Cloud => "cloud",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FogRenderDistanceType {
+    Render,
+    Fixed,
+}
+
+#[derive(Clone, Debug)]
+pub struct DistanceFog {
+    pub fog_start: f64,
+    pub fog_end: f64,
+    pub fog_color: RGBColor,
+    pub render_distance_type: FogRenderDistanceType,
+}
+
+impl DistanceFog {
+    pub fn new(
+        fog_start: f64,
+        fog_end: f64,
+        fog_color: RGBColor,
+        render_distance_type: FogRenderDistanceType,
+    ) -> Self {
+        Self {
+            fog_start,
+            fog_end,
+            fog_color,
+            render_distance_type,
+        }
+    }
+
+    fn serialize(&self) -> Value {
+        json!({
+            "fog_start": self.fog_start,
+            "fog_end": self.fog_end,
+            "fog_color": self.fog_color.render_as_hex(),
+            "render_distance_type": self.render_distance_type
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct VolumetricDensity {
+    pub max_density: f64,
+    pub max_density_height: f64,
+    pub zero_density_height: f64,
+    pub uniform: bool,
+}
+
+impl VolumetricDensity {
+    pub fn new(max_density: f64, max_density_height: f64, zero_density_height: f64) -> Self {
+        Self {
+            max_density,
+            max_density_height,
+            zero_density_height,
+            uniform: false,
+        }
+    }
+
+    pub fn uniform(max_density: f64) -> Self {
+        Self {
+            max_density,
+            max_density_height: 0.0,
+            zero_density_height: 0.0,
+            uniform: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MediaCoefficient {
+    pub scattering: [f64; 3],
+    pub absorption: [f64; 3],
+}
+
+impl MediaCoefficient {
+    pub fn new(scattering: [f64; 3], absorption: [f64; 3]) -> Self {
+        Self {
+            scattering,
+            absorption,
+        }
+    }
+}
+
+/// A fog setting, written to `RP/fogs`. Can be referenced from client biomes
+/// via `fog_identifier`.
+#[derive(Clone, Debug)]
+pub struct FogSetting {
+    id: Identifier,
+    pub format_version: SemVer,
+    pub distance: Vec<(FogMedium, DistanceFog)>,
+    pub density: Vec<(FogMedium, VolumetricDensity)>,
+    pub media_coefficients: Vec<(FogMedium, MediaCoefficient)>,
+}
+
+impl FogSetting {
+    pub fn new(id: Identifier) -> Self {
+        Self {
+            id,
+            format_version: SemVer::new(1, 16, 100),
+            distance: vec![],
+            density: vec![],
+            media_coefficients: vec![],
+        }
+    }
+
+    pub fn using_distance(self, medium: FogMedium, fog: DistanceFog) -> Self {
+        let mut distance = self.distance;
+        distance.retain(|(m, _)| *m != medium);
+        distance.push((medium, fog));
+
+        Self { distance, ..self }
+    }
+
+    pub fn using_density(self, medium: FogMedium, value: VolumetricDensity) -> Self {
+        let mut density = self.density;
+        density.retain(|(m, _)| *m != medium);
+        density.push((medium, value));
+
+        Self { density, ..self }
+    }
+
+    pub fn using_media_coefficient(self, medium: FogMedium, value: MediaCoefficient) -> Self {
+        let mut media_coefficients = self.media_coefficients;
+        media_coefficients.retain(|(m, _)| *m != medium);
+        media_coefficients.push((medium, value));
+
+        Self {
+            media_coefficients,
+            ..self
+        }
+    }
+
+    pub fn using_format_version(self, format_version: SemVer) -> Self {
+        Self {
+            format_version,
+            ..self
+        }
+    }
+
+    pub fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut settings = Map::new();
+        settings.insert(
+            "description".to_string(),
+            json!({ "identifier": self.id.render() }),
+        );
+
+        if !self.distance.is_empty() {
+            let mut distance = Map::new();
+            for (medium, fog) in &self.distance {
+                distance.insert(medium.str_medium().to_string(), fog.serialize());
+            }
+            settings.insert("distance".to_string(), Value::Object(distance));
+        }
+
+        if !self.density.is_empty() || !self.media_coefficients.is_empty() {
+            let mut volumetric = Map::new();
+
+            if !self.density.is_empty() {
+                let mut density = Map::new();
+                for (medium, value) in &self.density {
+                    density.insert(medium.str_medium().to_string(), json!(value));
+                }
+                volumetric.insert("density".to_string(), Value::Object(density));
+            }
+
+            if !self.media_coefficients.is_empty() {
+                let mut coefficients = Map::new();
+                for (medium, value) in &self.media_coefficients {
+                    coefficients.insert(medium.str_medium().to_string(), json!(value));
+                }
+                volumetric.insert("media_coefficients".to_string(), Value::Object(coefficients));
+            }
+
+            settings.insert("volumetric".to_string(), Value::Object(volumetric));
+        }
+
+        json!({
+            "format_version": self.format_version.render(),
+            "minecraft:fog_settings": settings
+        })
+        .to_string()
+    }
+}
@@ -1,6 +1,7 @@
 pub mod blend_modes;
 
 use crate::image::blend_modes::{overlay_blend_mode, BlendMode};
+use crate::vio::RGBColor;
 use hsl::HSL;
 use image::{Pixel, Rgba, RgbaImage};
 use std::path::PathBuf;
@@ -35,10 +36,67 @@ impl Image {
         }
     }
 
+    /// Like `new`, but returns `None` if the file can't be read as an image.
+    pub fn open(src: impl Into<PathBuf> + Clone) -> Option<Self> {
+        let img = image::ImageReader::open(src.clone().into())
+            .ok()?
+            .decode()
+            .ok()?
+            .to_rgba8();
+
+        Some(Self {
+            source: src.into(),
+            hue_shift: 0.0,
+            img,
+        })
+    }
+
     pub fn src(&self) -> PathBuf {
         self.source.clone()
     }
 
+    pub fn width(&self) -> u32 {
+        self.img.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.img.height()
+    }
+
+    /// The red, green, blue and alpha of a pixel.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        self.img.get_pixel(x, y).0
+    }
+
+    /// Stacks the frames from top to bottom, as flipbook textures expect.
+    /// Returns `None` if there are no frames or they aren't all squares of
+    /// the same size.
+    pub fn vertical_strip(frames: &[Image]) -> Option<Self> {
+        let width = frames.first()?.width();
+        if frames
+            .iter()
+            .any(|frame| frame.width() != width || frame.height() != width)
+        {
+            return None;
+        }
+
+        let height = width * frames.len() as u32;
+        let mut img = RgbaImage::new(width, height);
+        let mut y = 0;
+        for frame in frames {
+            for (x, frame_y, color) in frame.img.enumerate_pixels() {
+                img.put_pixel(x, y + frame_y, *color);
+            }
+            y += frame.height();
+        }
+
+        Some(Self {
+            source: frames[0].source.clone(),
+            hue_shift: 0.0,
+            img,
+        })
+    }
+
     pub fn build(&self, path: PathBuf) {
         let _ = &self.img.save(&path).unwrap();
     }
@@ -53,6 +111,24 @@ impl Image {
         self.clone()
     }
 
+    /// Multiplies every pixel by the given color, keeping the alpha.
+    pub fn with_tint(&self, tint: RGBColor) -> Self {
+        let mut tinted = self.clone();
+        let [tr, tg, tb] = tint.render_as_arr();
+
+        for (_, _, color) in tinted.img.enumerate_pixels_mut() {
+            let channels = color.channels();
+            *color = Rgba::from([
+                (channels[0] as u16 * tr as u16 / 255) as u8,
+                (channels[1] as u16 * tg as u16 / 255) as u8,
+                (channels[2] as u16 * tb as u16 / 255) as u8,
+                channels[3],
+            ]);
+        }
+
+        tinted
+    }
+
     fn shifted(color: Rgba<u8>, amount: f64) -> Rgba<u8> {
         let channels = color.channels();
         let fr = channels[0];
@@ -151,6 +227,68 @@ fn compose_overlay(a: RgbaImage, b: RgbaImage) -> RgbaImage {
     result_src
 }
 
+/// A channel of an image packed by `Image::from_channels`.
+#[derive(Clone)]
+pub enum Channel {
+    /// A grayscale image, of which the red channel is used.
+    Image(Image),
+    Value(u8),
+}
+
+impl Image {
+    /// Packs a channel source into each of red, green, blue and alpha.
+    /// Returns `None` if no channel is an image or the images differ in size.
+    pub fn from_channels(channels: [&Channel; 4]) -> Option<Self> {
+        let images: Vec<&Image> = channels
+            .iter()
+            .filter_map(|channel| match channel {
+                Channel::Image(image) => Some(image),
+                Channel::Value(_) => None,
+            })
+            .collect();
+        let (width, height) = (images.first()?.width(), images.first()?.height());
+        if images
+            .iter()
+            .any(|image| image.width() != width || image.height() != height)
+        {
+            return None;
+        }
+
+        let mut img = RgbaImage::new(width, height);
+        for (x, y, color) in img.enumerate_pixels_mut() {
+            *color = Rgba(channels.map(|channel| match channel {
+                Channel::Image(image) => image.img.get_pixel(x, y).channels()[0],
+                Channel::Value(value) => *value,
+            }));
+        }
+
+        Some(Self {
+            source: images[0].source.clone(),
+            hue_shift: 0.0,
+            img,
+        })
+    }
+}
+
+/// A way to recolor template textures, used by the item set generators.
+#[derive(Clone, Debug, Default)]
+pub enum Recolor {
+    #[default]
+    None,
+    HueShift(f64),
+    Tint(RGBColor),
+}
+
+impl Recolor {
+    pub fn apply(&self, image: &Image) -> Image {
+        match self {
+            Recolor::None => image.clone(),
+            Recolor::HueShift(amount) => image.clone().with_hue_shift(*amount),
+            Recolor::Tint(color) => image.with_tint(*color),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ComposeOptions {
     pub repeats: u8,
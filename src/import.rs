@@ -0,0 +1,245 @@
+//! Helpers reading an existing behavior and resource pack, used by `Pack::import`.
+
+use crate::block::block_registry::{
+    AllBlockAtlasEntry, BlockAtlasEntry, Faces, PerFaceBlockAtlasEntry,
+};
+use crate::image::Image;
+use crate::localization::Localization;
+use crate::vio::{json_field, Buildable, Identifier, ParseError, SemVer};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+const IMAGE_EXTENSIONS: [&str; 3] = ["png", "tga", "jpg"];
+
+/// The parts of a `manifest.json` a pack is rebuilt from.
+pub(crate) struct ImportedManifest {
+    pub name: String,
+    pub description: String,
+    pub author: String,
+    pub version: SemVer,
+    pub header_uuid: String,
+    pub module_uuid: String,
+    pub script_module_uuid: Option<String>,
+    pub server_version: Option<SemVer>,
+    pub server_ui_version: Option<SemVer>,
+}
+
+pub(crate) fn read_json(path: &Path) -> Result<Value, ParseError> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn version_from_array(value: &Value) -> Option<SemVer> {
+    let parts: Vec<i32> = value
+        .as_array()?
+        .iter()
+        .map(|part| part.as_i64().map(|part| part as i32))
+        .collect::<Option<Vec<i32>>>()?;
+
+    match parts.as_slice() {
+        [major, minor, patch] => Some(SemVer::new(*major, *minor, *patch)),
+        _ => None,
+    }
+}
+
+fn string_field(value: &Value, path: &[&str]) -> Result<String, ParseError> {
+    let field = json_field(value, path)?;
+
+    field
+        .as_str()
+        .map(|field| field.to_string())
+        .ok_or_else(|| ParseError::InvalidValue {
+            field: path.join("."),
+            value: field.to_string(),
+        })
+}
+
+pub(crate) fn read_manifest(path: &Path) -> Result<ImportedManifest, ParseError> {
+    let manifest = read_json(path)?;
+
+    let version_field = json_field(&manifest, &["header", "version"])?;
+    let version = version_from_array(version_field)
+        .or_else(|| version_field.as_str().and_then(SemVer::parse))
+        .ok_or_else(|| ParseError::InvalidValue {
+            field: "header.version".to_string(),
+            value: version_field.to_string(),
+        })?;
+
+    let modules = json_field(&manifest, &["modules"])?
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    let module_uuid = modules
+        .iter()
+        .find(|module| module["type"] != "script")
+        .and_then(|module| module["uuid"].as_str())
+        .ok_or_else(|| ParseError::MissingField("modules.uuid".to_string()))?
+        .to_string();
+    let script_module_uuid = modules
+        .iter()
+        .find(|module| module["type"] == "script")
+        .and_then(|module| module["uuid"].as_str())
+        .map(|uuid| uuid.to_string());
+
+    let dependency_version = |module_name: &str| {
+        manifest["dependencies"]
+            .as_array()?
+            .iter()
+            .find(|dependency| dependency["module_name"] == module_name)
+            .and_then(|dependency| dependency["version"].as_str())
+            .and_then(SemVer::parse)
+    };
+
+    Ok(ImportedManifest {
+        name: string_field(&manifest, &["header", "name"])?,
+        description: manifest["header"]["description"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        author: manifest["metadata"]["authors"][0]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        version,
+        header_uuid: string_field(&manifest, &["header", "uuid"])?,
+        module_uuid,
+        script_module_uuid,
+        server_version: dependency_version("@minecraft/server"),
+        server_ui_version: dependency_version("@minecraft/server-ui"),
+    })
+}
+
+/// All files below `root`, as sorted `/` separated paths relative to it.
+pub(crate) fn pack_files(root: &Path) -> Vec<String> {
+    let mut files = vec![];
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if let Ok(relative) = path.strip_prefix(root) {
+                let relative: Vec<String> = relative
+                    .components()
+                    .map(|part| part.as_os_str().to_string_lossy().to_string())
+                    .collect();
+                files.push(relative.join("/"));
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// Finds the image a texture path without extension (`textures/items/ruby`)
+/// points to. Returns the file path relative to the RP root and the image.
+pub(crate) fn find_texture(rp_dir: &Path, texture_path: &str) -> Option<(String, Image)> {
+    IMAGE_EXTENSIONS.iter().find_map(|extension| {
+        let file = format!("{}.{}", texture_path, extension);
+
+        Image::open(rp_dir.join(&file)).map(|image| (file, image))
+    })
+}
+
+/// The `texture_data` entries of an `item_texture.json` or
+/// `terrain_texture.json` that point to a single texture path.
+pub(crate) fn read_texture_data(atlas: &Value) -> (Vec<(String, String)>, Vec<String>) {
+    let mut entries = vec![];
+    let mut skipped = vec![];
+
+    if let Some(texture_data) = atlas["texture_data"].as_object() {
+        for (key, entry) in texture_data {
+            match entry["textures"].as_str() {
+                Some(path) => entries.push((key.clone(), path.to_string())),
+                None => skipped.push(key.clone()),
+            }
+        }
+    }
+
+    (entries, skipped)
+}
+
+/// Reads an entry of `blocks.json`. Entries that aren't keyed by a namespaced
+/// identifier or use per-face textures other than all six faces or
+/// `up`/`down`/`side` are not supported.
+pub(crate) fn read_block_atlas_entry(key: &str, entry: &Value) -> Option<Arc<dyn BlockAtlasEntry>> {
+    let id = Identifier::parse(key)?;
+    let sound = entry["sound"].as_str().unwrap_or_default();
+
+    match &entry["textures"] {
+        Value::String(textures) => Some(AllBlockAtlasEntry::new(id, textures, sound).build()),
+        Value::Object(faces) => {
+            let face = |name: &str| {
+                faces
+                    .get(name)
+                    .or_else(|| match name {
+                        "north" | "south" | "east" | "west" => faces.get("side"),
+                        _ => None,
+                    })
+                    .and_then(Value::as_str)
+            };
+
+            Some(
+                PerFaceBlockAtlasEntry::new(
+                    id,
+                    Faces::new(
+                        face("up")?,
+                        face("down")?,
+                        face("north")?,
+                        face("south")?,
+                        face("east")?,
+                        face("west")?,
+                    ),
+                    sound,
+                )
+                .build(),
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Reads a `.lang` file. Item and block names become `item_names` and
+/// `block_names`, every other entry is kept as a custom one.
+pub(crate) fn read_localization(language: &str, src: &str) -> Localization {
+    let mut localization = Localization::new(language);
+
+    for line in src.lines() {
+        let line = line.trim_start_matches('\u{feff}');
+        if line.trim().is_empty() || line.starts_with("##") {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = match value.find("\t#") {
+            Some(comment) => &value[..comment],
+            None => value,
+        }
+        .trim_end();
+
+        let name_of = |prefix: &str| {
+            key.strip_prefix(prefix)
+                .and_then(|key| key.strip_suffix(".name"))
+                .and_then(Identifier::parse)
+        };
+
+        if let Some(id) = name_of("item.") {
+            localization.add_item_name(id, value);
+        } else if let Some(id) = name_of("tile.") {
+            localization.add_block_name(id, value);
+        } else {
+            localization.add_custom(key, value);
+        }
+    }
+
+    localization
+}
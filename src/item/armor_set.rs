@@ -0,0 +1,323 @@
+use crate::image::{Image, Recolor};
+use crate::item::attachable::{Attachable, AttachableTexture};
+use crate::item::component::{
+    ItemDurabiltyComponent, ItemEnchantableComponent, ItemIconComponent, ItemMaxStackSizeComponent,
+    ItemRepairableComponent, ItemTagsComponent, ItemWearableComponent,
+};
+use crate::item::item_registry::ItemTexture;
+use crate::item::utils::{
+    capitalized, EnchantableSlot, ItemRepairEntry, ItemTextureDescriptor, ItemWearableSlot,
+};
+use crate::item::Item;
+use crate::localization::Localization;
+use crate::pack::Pack;
+use crate::recipe::{RecipeIO, ShapedRecipe};
+use crate::vio::{Buildable, Identifier, RangeDescriptor};
+
+/// Template textures the armor set is recolored from.
+#[derive(Clone)]
+pub struct ArmorTemplates {
+    pub helmet: Image,
+    pub chestplate: Image,
+    pub leggings: Image,
+    pub boots: Image,
+    pub layer_1: Image,
+    pub layer_2: Image,
+}
+
+impl ArmorTemplates {
+    pub fn new(
+        helmet: Image,
+        chestplate: Image,
+        leggings: Image,
+        boots: Image,
+        layer_1: Image,
+        layer_2: Image,
+    ) -> Self {
+        Self {
+            helmet,
+            chestplate,
+            leggings,
+            boots,
+            layer_1,
+            layer_2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ArmorProtection {
+    pub helmet: i32,
+    pub chestplate: i32,
+    pub leggings: i32,
+    pub boots: i32,
+}
+
+impl ArmorProtection {
+    pub fn new(helmet: i32, chestplate: i32, leggings: i32, boots: i32) -> Self {
+        Self {
+            helmet,
+            chestplate,
+            leggings,
+            boots,
+        }
+    }
+}
+
+/// Generates the four armor pieces of a material: items, icons, armor layer
+/// textures, attachables, crafting recipes and names.
+#[derive(Clone)]
+pub struct ArmorSet {
+    pub namespace: String,
+    pub material: String,
+    pub display_name: String,
+    pub templates: ArmorTemplates,
+    pub recolor: Recolor,
+    pub protection: ArmorProtection,
+    /// Vanilla-like durability multiplier, multiplied by 11/16/15/13 for each piece.
+    pub durability_multiplier: i32,
+    pub enchantability: u8,
+    pub repair_item: Identifier,
+}
+
+struct ArmorPiece {
+    name: &'static str,
+    slot: ItemWearableSlot,
+    enchantable_slot: EnchantableSlot,
+    base_durability: i32,
+    pattern: Vec<&'static str>,
+}
+
+impl ArmorSet {
+    pub fn new(
+        namespace: impl Into<String>,
+        material: impl Into<String>,
+        display_name: impl Into<String>,
+        templates: ArmorTemplates,
+        protection: ArmorProtection,
+        repair_item: Identifier,
+    ) -> Self {
+        Self {
+            namespace: namespace.into(),
+            material: material.into(),
+            display_name: display_name.into(),
+            templates,
+            recolor: Recolor::None,
+            protection,
+            durability_multiplier: 15,
+            enchantability: 9,
+            repair_item,
+        }
+    }
+
+    pub fn using_recolor(self, recolor: Recolor) -> Self {
+        Self { recolor, ..self }
+    }
+
+    pub fn using_durability_multiplier(self, durability_multiplier: i32) -> Self {
+        Self {
+            durability_multiplier,
+            ..self
+        }
+    }
+
+    pub fn using_enchantability(self, enchantability: u8) -> Self {
+        Self {
+            enchantability,
+            ..self
+        }
+    }
+
+    fn pieces() -> Vec<ArmorPiece> {
+        vec![
+            ArmorPiece {
+                name: "helmet",
+                slot: ItemWearableSlot::ArmorHead,
+                enchantable_slot: EnchantableSlot::ArmorHead,
+                base_durability: 11,
+                pattern: vec!["XXX", "X X"],
+            },
+            ArmorPiece {
+                name: "chestplate",
+                slot: ItemWearableSlot::ArmorChest,
+                enchantable_slot: EnchantableSlot::ArmorTorso,
+                base_durability: 16,
+                pattern: vec!["X X", "XXX", "XXX"],
+            },
+            ArmorPiece {
+                name: "leggings",
+                slot: ItemWearableSlot::ArmorLegs,
+                enchantable_slot: EnchantableSlot::ArmorLegs,
+                base_durability: 15,
+                pattern: vec!["XXX", "X X", "X X"],
+            },
+            ArmorPiece {
+                name: "boots",
+                slot: ItemWearableSlot::ArmorFeet,
+                enchantable_slot: EnchantableSlot::ArmorFeet,
+                base_durability: 13,
+                pattern: vec!["X X", "X X"],
+            },
+        ]
+    }
+
+    fn piece_id(&self, piece: &ArmorPiece) -> Identifier {
+        Identifier::new(&self.namespace, format!("{}_{}", self.material, piece.name))
+    }
+
+    fn piece_texture_id(&self, piece: &ArmorPiece) -> String {
+        format!("{}_{}_{}", self.namespace, self.material, piece.name)
+    }
+
+    fn piece_icon(&self, piece: &ArmorPiece) -> &Image {
+        match piece.slot {
+            ItemWearableSlot::ArmorHead => &self.templates.helmet,
+            ItemWearableSlot::ArmorChest => &self.templates.chestplate,
+            ItemWearableSlot::ArmorLegs => &self.templates.leggings,
+            _ => &self.templates.boots,
+        }
+    }
+
+    fn piece_protection(&self, piece: &ArmorPiece) -> i32 {
+        match piece.slot {
+            ItemWearableSlot::ArmorHead => self.protection.helmet,
+            ItemWearableSlot::ArmorChest => self.protection.chestplate,
+            ItemWearableSlot::ArmorLegs => self.protection.leggings,
+            _ => self.protection.boots,
+        }
+    }
+
+    fn piece_display_name(&self, piece: &ArmorPiece) -> String {
+        format!("{} {}", self.display_name, capitalized(piece.name))
+    }
+
+    /// The generated items, in helmet, chestplate, leggings, boots order.
+    pub fn items(&self) -> Vec<Item> {
+        Self::pieces()
+            .iter()
+            .map(|piece| {
+                Item::new(self.piece_id(piece)).using_components(vec![
+                    ItemIconComponent::new(ItemTextureDescriptor::new(self.piece_texture_id(piece)))
+                        .build(),
+                    ItemMaxStackSizeComponent::new(1).build(),
+                    ItemWearableComponent::new(self.piece_protection(piece), piece.slot.clone())
+                        .build(),
+                    ItemDurabiltyComponent::new(
+                        RangeDescriptor::new(60, 100),
+                        piece.base_durability * self.durability_multiplier,
+                    )
+                    .build(),
+                    ItemRepairableComponent::new(vec![ItemRepairEntry::new(
+                        vec![self.repair_item.render()],
+                        "q.max_durability * 0.25",
+                    )])
+                    .build(),
+                    ItemEnchantableComponent::new(
+                        self.enchantability,
+                        piece.enchantable_slot.clone(),
+                    )
+                    .build(),
+                    ItemTagsComponent::new(vec![
+                        "minecraft:is_armor".to_string(),
+                        "minecraft:trimmable_armors".to_string(),
+                    ])
+                    .build(),
+                ])
+            })
+            .collect()
+    }
+
+    pub fn icons(&self) -> Vec<ItemTexture> {
+        Self::pieces()
+            .iter()
+            .map(|piece| {
+                ItemTexture::new(
+                    self.piece_texture_id(piece),
+                    format!("{}_{}", self.material, piece.name),
+                    self.recolor.apply(self.piece_icon(piece)),
+                )
+            })
+            .collect()
+    }
+
+    /// Attachables rendering the recolored armor layers on the player. Only
+    /// the first piece of a layer writes its texture, the others refer to it.
+    pub fn attachables(&self) -> Vec<Attachable> {
+        let layer_1 = self.recolor.apply(&self.templates.layer_1);
+        let layer_2 = self.recolor.apply(&self.templates.layer_2);
+        let mut written_layers = vec![];
+
+        Self::pieces()
+            .iter()
+            .filter_map(|piece| {
+                let (layer, layer_name) = match piece.slot {
+                    ItemWearableSlot::ArmorLegs => (layer_2.clone(), "layer_2"),
+                    _ => (layer_1.clone(), "layer_1"),
+                };
+
+                let mut attachable = Attachable::for_wearable(
+                    &Item::new(self.piece_id(piece)),
+                    &piece.slot,
+                    layer,
+                    format!("{}_{}_{}", self.namespace, self.material, layer_name),
+                )?;
+
+                if written_layers.contains(&layer_name) {
+                    attachable.textures = attachable
+                        .textures
+                        .into_iter()
+                        .map(|(key, texture)| (key, AttachableTexture::path(texture.render_path())))
+                        .collect();
+                } else {
+                    written_layers.push(layer_name);
+                }
+
+                Some(attachable)
+            })
+            .collect()
+    }
+
+    pub fn recipes(&self) -> Vec<ShapedRecipe> {
+        Self::pieces()
+            .iter()
+            .map(|piece| {
+                let id = self.piece_id(piece);
+
+                ShapedRecipe::new(
+                    Identifier::new(&self.namespace, format!("{}_recipe", id.value)),
+                    RecipeIO::new_typed(id).using_count(1),
+                )
+                .using_ingredients(vec![
+                    RecipeIO::new_typed(self.repair_item.clone()).using_key('X')
+                ])
+                .using_pattern(piece.pattern.clone())
+                .using_tags(vec!["crafting_table"])
+            })
+            .collect()
+    }
+
+    pub fn add_names(&self, localization: &mut Localization) {
+        for piece in Self::pieces() {
+            localization.add_item_name(self.piece_id(&piece), self.piece_display_name(&piece));
+        }
+    }
+
+    /// Registers everything the set consists of in the pack and adds the item
+    /// names to the given localization.
+    pub fn register(&self, pack: &mut Pack, localization: &mut Localization) {
+        for icon in self.icons() {
+            pack.register_item_texture(icon);
+        }
+        for item in self.items() {
+            pack.register_item(item);
+        }
+        for attachable in self.attachables() {
+            pack.register_attachable(attachable);
+        }
+        for recipe in self.recipes() {
+            pack.register_recipe(recipe.build());
+        }
+
+        self.add_names(localization);
+    }
+}
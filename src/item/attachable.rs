@@ -0,0 +1,237 @@
+use crate::image::Image;
+use crate::item::utils::ItemWearableSlot;
+use crate::item::Item;
+use crate::vio::{Identifier, SemVer};
+use derive_setters::Setters;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+const GLINT_TEXTURE: &str = "textures/misc/enchanted_actor_glint";
+
+/// A texture of an attachable. `Image` textures are written to `RP/<path>.png`.
+#[derive(Clone)]
+pub enum AttachableTexture {
+    Path(String),
+    Image { path: String, src: Image },
+}
+
+impl AttachableTexture {
+    pub fn path(path: impl Into<String>) -> Self {
+        Self::Path(path.into())
+    }
+
+    /// `path` is relative to the RP root and has no extension, e.g. `textures/models/armor/ruby_1`.
+    pub fn image(path: impl Into<String>, src: Image) -> Self {
+        Self::Image {
+            path: path.into(),
+            src,
+        }
+    }
+
+    pub fn render_path(&self) -> String {
+        match self {
+            AttachableTexture::Path(path) => path.clone(),
+            AttachableTexture::Image { path, .. } => path.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Default, Setters)]
+#[setters(prefix = "using_")]
+pub struct AttachableScripts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[setters(strip_option, into)]
+    pub parent_setup: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub pre_animation: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub animate: Vec<String>,
+}
+
+impl AttachableScripts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// An attachable, written to `RP/attachables`. Describes how an item is
+/// rendered when worn or held.
+#[derive(Clone)]
+pub struct Attachable {
+    id: Identifier,
+    pub format_version: SemVer,
+    pub materials: Vec<(String, String)>,
+    pub textures: Vec<(String, AttachableTexture)>,
+    pub geometry: Vec<(String, String)>,
+    pub render_controllers: Vec<String>,
+    pub scripts: Option<AttachableScripts>,
+    pub enchanted_glint: Option<String>,
+}
+
+impl Attachable {
+    pub fn new(id: Identifier) -> Self {
+        Self {
+            id,
+            format_version: SemVer::new(1, 10, 0),
+            materials: vec![],
+            textures: vec![],
+            geometry: vec![],
+            render_controllers: vec![],
+            scripts: None,
+            enchanted_glint: None,
+        }
+    }
+
+    /// Derives the attachable for an armor piece worn in the given slot, using
+    /// the vanilla humanoid armor geometry. `texture` is the armor layer
+    /// texture, written to `RP/textures/models/armor/<texture_name>.png`.
+    ///
+    /// Returns `None` for slots that have no armor geometry.
+    pub fn for_wearable(
+        item: &Item,
+        slot: &ItemWearableSlot,
+        texture: Image,
+        texture_name: impl Into<String>,
+    ) -> Option<Self> {
+        let (geometry, layer_variable) = match slot {
+            ItemWearableSlot::ArmorHead => ("geometry.humanoid.armor.helmet", "helmet_layer_visible"),
+            ItemWearableSlot::ArmorChest => {
+                ("geometry.humanoid.armor.chestplate", "chest_layer_visible")
+            }
+            ItemWearableSlot::ArmorLegs => ("geometry.humanoid.armor.leggings", "leg_layer_visible"),
+            ItemWearableSlot::ArmorFeet => ("geometry.humanoid.armor.boots", "boot_layer_visible"),
+            ItemWearableSlot::WeaponOffhand => return None,
+        };
+
+        Some(
+            Self::new(item.type_id())
+                .using_material("default", "armor")
+                .using_texture(
+                    "default",
+                    AttachableTexture::image(
+                        format!("textures/models/armor/{}", texture_name.into()),
+                        texture,
+                    ),
+                )
+                .using_geometry("default", geometry)
+                .using_render_controllers(vec!["controller.render.armor"])
+                .using_scripts(
+                    AttachableScripts::new()
+                        .using_parent_setup(format!("variable.{} = 0.0;", layer_variable)),
+                )
+                .using_enchanted_glint("armor_enchanted"),
+        )
+    }
+
+    pub fn id(&self) -> Identifier {
+        self.id.clone()
+    }
+
+    pub fn using_material(self, key: impl Into<String>, material: impl Into<String>) -> Self {
+        let mut materials = self.materials;
+        materials.push((key.into(), material.into()));
+
+        Self { materials, ..self }
+    }
+
+    pub fn using_texture(self, key: impl Into<String>, texture: AttachableTexture) -> Self {
+        let mut textures = self.textures;
+        textures.push((key.into(), texture));
+
+        Self { textures, ..self }
+    }
+
+    pub fn using_geometry(self, key: impl Into<String>, geometry: impl Into<String>) -> Self {
+        let mut geometries = self.geometry;
+        geometries.push((key.into(), geometry.into()));
+
+        Self {
+            geometry: geometries,
+            ..self
+        }
+    }
+
+    pub fn using_render_controllers(self, render_controllers: Vec<impl Into<String>>) -> Self {
+        Self {
+            render_controllers: render_controllers.into_iter().map(|x| x.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn using_scripts(self, scripts: AttachableScripts) -> Self {
+        Self {
+            scripts: Some(scripts),
+            ..self
+        }
+    }
+
+    /// Adds the `enchanted` material and glint texture, so the attachable
+    /// shimmers when the item is enchanted.
+    pub fn using_enchanted_glint(self, material: impl Into<String>) -> Self {
+        Self {
+            enchanted_glint: Some(material.into()),
+            ..self
+        }
+    }
+
+    pub fn using_format_version(self, format_version: SemVer) -> Self {
+        Self {
+            format_version,
+            ..self
+        }
+    }
+
+    /// The `Image` textures of this attachable paired with their RP relative paths.
+    pub fn images(&self) -> Vec<(String, Image)> {
+        self.textures
+            .iter()
+            .filter_map(|(_, texture)| match texture {
+                AttachableTexture::Image { path, src } => Some((path.clone(), src.clone())),
+                AttachableTexture::Path(_) => None,
+            })
+            .collect()
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut materials = Map::new();
+        for (key, material) in &self.materials {
+            materials.insert(key.clone(), json!(material));
+        }
+
+        let mut textures = Map::new();
+        for (key, texture) in &self.textures {
+            textures.insert(key.clone(), json!(texture.render_path()));
+        }
+
+        if let Some(material) = &self.enchanted_glint {
+            materials.insert("enchanted".to_string(), json!(material));
+            textures.insert("enchanted".to_string(), json!(GLINT_TEXTURE));
+        }
+
+        let mut geometry = Map::new();
+        for (key, geo) in &self.geometry {
+            geometry.insert(key.clone(), json!(geo));
+        }
+
+        let mut description = Map::new();
+        description.insert("identifier".to_string(), json!(self.id.render()));
+        description.insert("materials".to_string(), Value::Object(materials));
+        description.insert("textures".to_string(), Value::Object(textures));
+        description.insert("geometry".to_string(), Value::Object(geometry));
+        description.insert(
+            "render_controllers".to_string(),
+            json!(self.render_controllers),
+        );
+        if let Some(scripts) = &self.scripts {
+            description.insert("scripts".to_string(), json!(scripts));
+        }
+
+        json!({
+            "format_version": self.format_version.render(),
+            "minecraft:attachable": {
+                "description": description
+            }
+        })
+        .to_string()
+    }
+}
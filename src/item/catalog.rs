@@ -0,0 +1,119 @@
+use crate::block::Block;
+use crate::item::utils::MenuCategoryName;
+use crate::item::Item;
+use crate::localization::Localization;
+use crate::vio::Identifier;
+use serde_json::{json, Value};
+
+/// A custom creative inventory group, written to
+/// `BP/item_catalog/crafting_item_catalog.json`.
+///
+/// Items and blocks whose menu category references the group are added to it
+/// automatically when the pack is generated.
+#[derive(Clone, Debug)]
+pub struct CreativeGroup {
+    pub id: Identifier,
+    pub category: MenuCategoryName,
+    pub icon: Identifier,
+    pub items: Vec<Identifier>,
+}
+
+impl CreativeGroup {
+    pub fn new(id: Identifier, category: MenuCategoryName, icon: Identifier) -> Self {
+        Self {
+            id,
+            category,
+            icon,
+            items: vec![],
+        }
+    }
+
+    pub fn using_items(self, items: Vec<Identifier>) -> Self {
+        Self { items, ..self }
+    }
+
+    /// Adds the localized name of the group.
+    pub fn add_name(&self, localization: &mut Localization, name: impl Into<String>) {
+        localization.add_custom(self.id.render(), name);
+    }
+
+    fn collect_items(&self, items: &[Item], blocks: &[Block]) -> Vec<String> {
+        let group = self.id.render();
+        let mut collected: Vec<String> = self.items.iter().map(|id| id.render()).collect();
+
+        let grouped_items = items
+            .iter()
+            .filter(|item| {
+                item.menu_category
+                    .as_ref()
+                    .is_some_and(|category| category.group.as_ref() == Some(&group))
+            })
+            .map(|item| item.type_id().render());
+
+        let grouped_blocks = blocks
+            .iter()
+            .filter(|block| {
+                block
+                    .menu_category
+                    .as_ref()
+                    .is_some_and(|category| category.group.as_ref() == Some(&group))
+            })
+            .map(|block| block.type_id().render());
+
+        for id in grouped_items.chain(grouped_blocks) {
+            if !collected.contains(&id) {
+                collected.push(id);
+            }
+        }
+
+        collected
+    }
+
+    fn serialize(&self, items: &[Item], blocks: &[Block]) -> Value {
+        json!({
+            "group_identifier": {
+                "icon": self.icon.render(),
+                "name": self.id.render()
+            },
+            "items": self.collect_items(items, blocks)
+        })
+    }
+}
+
+pub fn serialize_crafting_item_catalog(
+    groups: &[CreativeGroup],
+    items: &[Item],
+    blocks: &[Block],
+) -> String {
+    let mut categories: Vec<(MenuCategoryName, Vec<Value>)> = vec![];
+
+    for group in groups {
+        let serialized = group.serialize(items, blocks);
+
+        match categories
+            .iter_mut()
+            .find(|(category, _)| *category == group.category)
+        {
+            Some((_, groups)) => groups.push(serialized),
+            None => categories.push((group.category.clone(), vec![serialized])),
+        }
+    }
+
+    let categories: Vec<Value> = categories
+        .into_iter()
+        .map(|(category, groups)| {
+            json!({
+                "category_name": category,
+                "groups": groups
+            })
+        })
+        .collect();
+
+    json!({
+        "format_version": "1.21.60",
+        "minecraft:crafting_items_catalog": {
+            "categories": categories
+        }
+    })
+    .to_string()
+}
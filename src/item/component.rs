@@ -1,15 +1,21 @@
 use crate::block::utils::BlockDestroySpeed;
 use crate::item::utils::{
     DurabilityThreshold, EnchantableSlot, ItemAnimation, ItemRarity, ItemRepairEntry,
-    ItemTextureDescriptor, ItemWearableSlot,
+    ItemTextureDescriptor, ItemWearableSlot, KineticWeaponConditions, ShooterAmmunition,
 };
+use crate::tag::{Tag, TagQuery};
 use crate::vio::ViolaDefault;
-use crate::vio::{Buildable, ColorCode, Identifier, RangeDescriptor};
+use crate::vio::{json_equivalent, Buildable, ColorCode, Identifier, RangeDescriptor};
 use item_component_macros::item_component;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::sync::Arc;
 use viola::ViolaDefault;
 
 pub trait ItemComponent {
-    fn serialize(&self) -> String;
+    /// The key of the component, e.g. `minecraft:damage`.
+    fn id(&self) -> String;
+    fn value(&self) -> serde_json::Value;
 }
 
 // * ItemDamageComponent
@@ -77,6 +83,7 @@ item_component! {
 }
 
 // * ItemCreativeCategoryComponent
+// Deprecated by the game, use `Item::using_menu_category` instead.
 
 item_component! {
     name = CreativeCategory for "minecraft:creative_category";
@@ -134,6 +141,16 @@ item_component! {
     destroy_speeds has Vec<BlockDestroySpeed> for "destroy_speeds" with "public";
 }
 
+impl ItemDiggerComponent {
+    /// Digs the blocks matching the query at `speed`, with efficiency.
+    pub fn for_tags(query: &TagQuery, speed: i32) -> Self {
+        Self::new(
+            true,
+            vec![BlockDestroySpeed::new(query.block_descriptor(), speed)],
+        )
+    }
+}
+
 // * Enchantable
 
 item_component! {
@@ -236,6 +253,12 @@ item_component! {
     tags has Vec<String> for "tags" with "public";
 }
 
+impl ItemTagsComponent {
+    pub fn from_tags(tags: &[Tag]) -> Self {
+        Self::new(tags.iter().map(|tag| tag.name().to_string()).collect())
+    }
+}
+
 // * Throwable
 
 item_component! {
@@ -248,6 +271,16 @@ item_component! {
     scale_power_by_draw_duration has bool for "scale_power_by_draw_duration" with "public";
 }
 
+// * Food
+
+item_component! {
+    name = Food for "minecraft:food";
+    nutrition has i32 for "nutrition" with "public";
+    saturation_modifier has f64 for "saturation_modifier" with "public";
+    can_always_eat has bool for "can_always_eat" with "public";
+    using_converts_to has Option<Identifier> for "using_converts_to" with "public" "skip_none";
+}
+
 // * UseAnimation
 
 item_component! {
@@ -292,3 +325,160 @@ item_component! {
     block has Identifier for "block" with "public";
     use_on has Vec<Identifier> for "use_on" with "public";
 }
+
+// * Shooter
+
+item_component! {
+    name = Shooter for "minecraft:shooter";
+    ammunition has Vec<ShooterAmmunition> for "ammunition" with "public";
+    charge_on_draw has bool for "charge_on_draw" with "public";
+    max_draw_duration has f64 for "max_draw_duration" with "public";
+    scale_power_by_draw_duration has bool for "scale_power_by_draw_duration" with "public";
+}
+
+// * Compostable
+
+item_component! {
+    name = Compostable for "minecraft:compostable";
+    composting_chance has u8 for "composting_chance" with "public";
+}
+
+// * SwingDuration
+
+item_component! {
+    name = SwingDuration for "minecraft:swing_duration";
+    value has f64 for "value" with "public";
+}
+
+// * PiercingWeapon
+
+item_component! {
+    name = PiercingWeapon for "minecraft:piercing_weapon";
+    reach has RangeDescriptor<f64> for "reach" with "public";
+    creative_reach has Option<RangeDescriptor<f64>> for "creative_reach" with "public" "skip_none" "using" "setter_only";
+    hitbox_margin has Option<f64> for "hitbox_margin" with "public" "skip_none" "using" "setter_only";
+}
+
+// * KineticWeapon
+
+item_component! {
+    name = KineticWeapon for "minecraft:kinetic_weapon";
+    delay has i32 for "delay" with "public";
+    reach has RangeDescriptor<f64> for "reach" with "public";
+    damage_multiplier has f64 for "damage_multiplier" with "public";
+    damage_modifier has f64 for "damage_modifier" with "public";
+    creative_reach has Option<RangeDescriptor<f64>> for "creative_reach" with "public" "skip_none" "using" "setter_only";
+    hitbox_margin has Option<f64> for "hitbox_margin" with "public" "skip_none" "using" "setter_only";
+    damage_conditions has Option<KineticWeaponConditions> for "damage_conditions" with "public" "skip_none" "using" "setter_only";
+    knockback_conditions has Option<KineticWeaponConditions> for "knockback_conditions" with "public" "skip_none" "using" "setter_only";
+    dismount_conditions has Option<KineticWeaponConditions> for "dismount_conditions" with "public" "skip_none" "using" "setter_only";
+}
+
+// * ItemRawComponent
+
+/// A component Violin has no typed struct for, kept as raw JSON.
+#[derive(Clone, Debug)]
+pub struct ItemRawComponent {
+    pub id: String,
+    pub value: Value,
+}
+
+impl ItemRawComponent {
+    pub fn new(id: impl Into<String>, value: Value) -> Self {
+        Self {
+            id: id.into(),
+            value,
+        }
+    }
+}
+
+impl ItemComponent for ItemRawComponent {
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn value(&self) -> Value {
+        self.value.clone()
+    }
+}
+
+impl Buildable for ItemRawComponent {}
+
+/// Reads a component of existing item JSON. Known components are mapped to
+/// their typed struct when it represents the value without loss, everything
+/// else is kept as a `ItemRawComponent`.
+pub fn item_component_from_json(id: &str, value: &Value) -> Arc<dyn ItemComponent> {
+    let typed = match id {
+        "minecraft:damage" => typed_item_component::<ItemDamageComponent>(value),
+        "minecraft:display_name" => typed_item_component::<ItemDisplayNameComponent>(value),
+        "minecraft:icon" => typed_item_component::<ItemIconComponent>(value),
+        "minecraft:fuel" => typed_item_component::<ItemFuelComponent>(value),
+        "minecraft:hand_equipped" => typed_item_component::<ItemHandEquippedComponent>(value),
+        "minecraft:allow_off_hand" => typed_item_component::<ItemAllowOffHandComponent>(value),
+        "minecraft:max_stack_size" => typed_item_component::<ItemMaxStackSizeComponent>(value),
+        "minecraft:durability" => typed_item_component::<ItemDurabiltyComponent>(value),
+        "minecraft:armor" => typed_item_component::<ItemArmorComponent>(value),
+        "minecraft:creative_category" => {
+            typed_item_component::<ItemCreativeCategoryComponent>(value)
+        }
+        "minecraft:repairable" => typed_item_component::<ItemRepairableComponent>(value),
+        "minecraft:custom_components" => {
+            typed_item_component::<ItemCustomComponentsComponent>(value)
+        }
+        "minecraft:bundle_interaction" => {
+            typed_item_component::<ItemBundleInteractionComponent>(value)
+        }
+        "minecraft:can_destroy_in_creative" => {
+            typed_item_component::<ItemCanDestroyInCreativeComponent>(value)
+        }
+        "minecraft:cooldown" => typed_item_component::<ItemCooldownComponent>(value),
+        "minecraft:damage_absorption" => {
+            typed_item_component::<ItemDamageAbsorptionComponent>(value)
+        }
+        "minecraft:digger" => typed_item_component::<ItemDiggerComponent>(value),
+        "minecraft:enchantable" => typed_item_component::<ItemEnchantableComponent>(value),
+        "minecraft:entity_placer" => typed_item_component::<ItemEntityPlacerComponent>(value),
+        "minecraft:glint" => typed_item_component::<ItemGlintComponent>(value),
+        "minecraft:hover_text_color" => typed_item_component::<ItemHoverTextColorComponent>(value),
+        "minecraft:durability_sensor" => {
+            typed_item_component::<ItemDurabilitySensorComponent>(value)
+        }
+        "minecraft:dyeable" => typed_item_component::<ItemDyeableComponent>(value),
+        "minecraft:interact_button" => typed_item_component::<ItemInteractButtonComponent>(value),
+        "minecraft:liquid_clipped" => typed_item_component::<ItemLiquidClippedComponent>(value),
+        "minecraft:projectile" => typed_item_component::<ItemProjectileComponent>(value),
+        "minecraft:rarity" => typed_item_component::<ItemRarityComponent>(value),
+        "minecraft:record" => typed_item_component::<ItemRecordComponent>(value),
+        "minecraft:storage_item" => typed_item_component::<ItemStorageItemComponent>(value),
+        "minecraft:tags" => typed_item_component::<ItemTagsComponent>(value),
+        "minecraft:throwable" => typed_item_component::<ItemThrowableComponent>(value),
+        "minecraft:food" => typed_item_component::<ItemFoodComponent>(value),
+        "minecraft:use_animation" => typed_item_component::<ItemUseAnimationComponent>(value),
+        "minecraft:use_modifiers" => typed_item_component::<ItemUseModifiersComponent>(value),
+        "minecraft:stacked_by_data" => typed_item_component::<ItemStackedByDataComponent>(value),
+        "minecraft:should_despawn" => typed_item_component::<ItemShouldDespawnComponent>(value),
+        "minecraft:wearable" => typed_item_component::<ItemWearableComponent>(value),
+        "minecraft:block_placer" => typed_item_component::<ItemBlockPlacerComponent>(value),
+        "minecraft:shooter" => typed_item_component::<ItemShooterComponent>(value),
+        "minecraft:compostable" => typed_item_component::<ItemCompostableComponent>(value),
+        "minecraft:swing_duration" => typed_item_component::<ItemSwingDurationComponent>(value),
+        "minecraft:piercing_weapon" => typed_item_component::<ItemPiercingWeaponComponent>(value),
+        "minecraft:kinetic_weapon" => typed_item_component::<ItemKineticWeaponComponent>(value),
+        _ => None,
+    };
+
+    typed.unwrap_or_else(|| ItemRawComponent::new(id, value.clone()).build())
+}
+
+fn typed_item_component<T>(value: &Value) -> Option<Arc<dyn ItemComponent>>
+where
+    T: ItemComponent + DeserializeOwned + 'static,
+{
+    let component: T = serde_json::from_value(value.clone()).ok()?;
+
+    if json_equivalent(&component.value(), value) {
+        Some(Arc::new(component))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,143 @@
+use crate::item::component::{
+    ItemCustomComponentsComponent, ItemFoodComponent, ItemIconComponent, ItemUseAnimationComponent,
+    ItemUseModifiersComponent,
+};
+use crate::item::utils::{ItemAnimation, ItemTextureDescriptor};
+use crate::item::Item;
+use crate::logger::warn;
+use crate::pack::Pack;
+use crate::vio::{Buildable, Identifier};
+use crate::vio_vanilla::script_additions::{ConsumeEffect, ConsumeEffects};
+
+/// An edible item: `minecraft:food` with the eating animation and use
+/// modifiers, plus optional effects applied through a generated script
+/// custom component.
+#[derive(Clone)]
+pub struct FoodItem {
+    pub id: Identifier,
+    pub nutrition: i32,
+    pub saturation_modifier: f64,
+    pub can_always_eat: bool,
+    pub using_converts_to: Option<Identifier>,
+    pub use_duration: f64,
+    pub movement_modifier: f64,
+    pub icon: Option<String>,
+    pub effects: Vec<ConsumeEffect>,
+}
+
+impl FoodItem {
+    pub fn new(id: Identifier, nutrition: i32, saturation_modifier: f64) -> Self {
+        Self {
+            id,
+            nutrition,
+            saturation_modifier,
+            can_always_eat: false,
+            using_converts_to: None,
+            use_duration: 1.6,
+            movement_modifier: 0.35,
+            icon: None,
+            effects: vec![],
+        }
+    }
+
+    pub fn using_can_always_eat(self, can_always_eat: bool) -> Self {
+        Self {
+            can_always_eat,
+            ..self
+        }
+    }
+
+    pub fn using_converts_to(self, item: Identifier) -> Self {
+        Self {
+            using_converts_to: Some(item),
+            ..self
+        }
+    }
+
+    /// Use duration in seconds and movement modifier while eating.
+    pub fn using_use_modifiers(self, use_duration: f64, movement_modifier: f64) -> Self {
+        Self {
+            use_duration,
+            movement_modifier,
+            ..self
+        }
+    }
+
+    pub fn using_icon(self, texture: impl Into<String>) -> Self {
+        Self {
+            icon: Some(texture.into()),
+            ..self
+        }
+    }
+
+    pub fn using_effects(self, effects: Vec<ConsumeEffect>) -> Self {
+        Self { effects, ..self }
+    }
+
+    /// The id of the custom component applying the effects.
+    pub fn effects_component_id(&self) -> Identifier {
+        Identifier::new(&self.id.namespace, format!("{}_effects", self.id.value))
+    }
+
+    pub fn item(&self) -> Item {
+        let mut item = Item::new(self.id.clone()).using_components(vec![
+            ItemFoodComponent::new(
+                self.nutrition,
+                self.saturation_modifier,
+                self.can_always_eat,
+                self.using_converts_to.clone(),
+            )
+            .build(),
+            ItemUseAnimationComponent::new(ItemAnimation::Eat).build(),
+            ItemUseModifiersComponent::new(self.use_duration, self.movement_modifier).build(),
+        ]);
+
+        if let Some(icon) = &self.icon {
+            item = item.with_component(
+                ItemIconComponent::new(ItemTextureDescriptor::new(icon.clone())).build(),
+            );
+        }
+
+        if !self.effects.is_empty() {
+            item = item.with_component(
+                ItemCustomComponentsComponent::new(vec![self.effects_component_id()]).build(),
+            );
+        }
+
+        item
+    }
+
+    /// The script addition applying the effects, if the food has any.
+    pub fn script_addition(&self) -> Option<ConsumeEffects> {
+        if self.effects.is_empty() {
+            return None;
+        }
+
+        Some(ConsumeEffects::new(
+            self.effects_component_id(),
+            self.effects.clone(),
+        ))
+    }
+
+    /// Registers the item and, if the food has effects, its script addition.
+    /// The effects need a pack with scripts. Without them the item is still
+    /// registered, but eating it applies no effects.
+    pub fn register(&self, pack: &mut Pack) {
+        pack.register_item(self.item());
+
+        if let Some(addition) = self.script_addition() {
+            if !pack.has_scripts() {
+                warn(
+                    format!(
+                        "Food \"{}\" has effects, but the pack has no scripts to apply them",
+                        self.id.render()
+                    ),
+                    "[ FOOD ]".to_string(),
+                );
+                return;
+            }
+
+            pack.add_script_addition(addition.build());
+        }
+    }
+}
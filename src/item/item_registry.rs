@@ -1,3 +1,4 @@
+use super::attachable::Attachable;
 use super::Item;
 use crate::image::Image;
 use crate::vio::ViolaDefault;
@@ -8,6 +9,7 @@ use viola::ViolaDefault;
 pub struct ItemRegistry {
     pub items: Vec<Item>,
     pub item_atlas: Vec<ItemTexture>,
+    pub attachables: Vec<Attachable>,
 }
 
 #[derive(Template)]
@@ -65,6 +67,7 @@ impl ItemRegistry {
         Self {
             items: vec![],
             item_atlas: vec![],
+            attachables: vec![],
         }
     }
 
@@ -75,4 +78,8 @@ impl ItemRegistry {
     pub fn add_texture(&mut self, entry: ItemTexture) {
         self.item_atlas.push(entry);
     }
+
+    pub fn add_attachable(&mut self, attachable: Attachable) {
+        self.attachables.push(attachable);
+    }
 }
@@ -1,12 +1,24 @@
 use crate::vio::ViolaDefault;
 use std::sync::Arc;
 
-use crate::vio::{Identifier, SemVer};
+use crate::item::component::item_component_from_json;
+use crate::item::utils::MenuCategory;
+use crate::tag::Tag;
+use crate::vio::{
+    components_to_map, json_field, json_format_version, json_identifier,
+    DuplicateComponentError, Identifier, ParseError, SemVer,
+};
 use askama::Template;
+use serde_json::{json, Map, Value};
 use viola::ViolaDefault;
 
+pub mod armor_set;
+pub mod attachable;
+pub mod catalog;
 pub mod component;
+pub mod food;
 pub mod item_registry;
+pub mod tool_set;
 pub mod utils;
 
 #[derive(Clone, Default, ViolaDefault)]
@@ -14,25 +26,79 @@ pub struct Item {
     pub type_id: Identifier,
     pub format_version: SemVer,
     pub components: Vec<Arc<dyn component::ItemComponent>>,
+    pub menu_category: Option<MenuCategory>,
 }
 
 impl Item {
-    pub fn serialize(&self) -> String {
-        let components = self.components.clone();
-        let mut components_strings: Vec<String> = vec![];
-        for component in components {
-            let mut fser = component.serialize();
-            fser.push(',');
-            components_strings.push(fser);
+    /// The components as a JSON object, keyed by component id.
+    pub fn components_value(&self) -> Result<Map<String, Value>, DuplicateComponentError> {
+        components_to_map(
+            self.components
+                .iter()
+                .map(|component| (component.id(), component.value())),
+        )
+    }
+
+    pub fn to_value(&self) -> Result<Value, DuplicateComponentError> {
+        let mut description = Map::new();
+        description.insert("identifier".to_string(), json!(self.type_id.render()));
+        if let Some(menu_category) = &self.menu_category {
+            description.insert("menu_category".to_string(), json!(menu_category));
         }
-        components_strings.last_mut().unwrap().pop();
-        ItemTemplate {
-            id: &self.type_id.render(),
-            components: components_strings,
-            format_version: self.format_version.render(),
+
+        Ok(json!({
+            "format_version": self.format_version.render(),
+            "minecraft:item": {
+                "description": description,
+                "components": self.components_value()?
+            }
+        }))
+    }
+
+    /// Serializes the item, panicking if two components share the same id.
+    pub fn serialize(&self) -> String {
+        match self.to_value() {
+            Ok(value) => value.to_string(),
+            Err(err) => panic!("Item \"{}\": {}", self.type_id.render(), err),
         }
-        .render()
-        .unwrap()
+    }
+
+    /// Reads an existing item definition. Components are mapped as described
+    /// in `item_component_from_json`.
+    pub fn from_json(src: &str) -> Result<Self, ParseError> {
+        Self::from_value(&serde_json::from_str(src)?)
+    }
+
+    pub fn from_value(value: &Value) -> Result<Self, ParseError> {
+        let format_version = json_format_version(value)?;
+        let type_id = json_identifier(value, &["minecraft:item", "description", "identifier"])?;
+        let description = json_field(value, &["minecraft:item", "description"])?;
+
+        let menu_category = match description.get("menu_category") {
+            Some(menu_category) => Some(serde_json::from_value(menu_category.clone())?),
+            None => None,
+        };
+
+        let components = match value["minecraft:item"].get("components") {
+            Some(Value::Object(components)) => components
+                .iter()
+                .map(|(id, component)| item_component_from_json(id, component))
+                .collect(),
+            Some(components) => {
+                return Err(ParseError::InvalidValue {
+                    field: "minecraft:item.components".to_string(),
+                    value: components.to_string(),
+                })
+            }
+            None => vec![],
+        };
+
+        Ok(Self {
+            type_id,
+            format_version,
+            components,
+            menu_category,
+        })
     }
 
     pub fn new(type_id: Identifier) -> Self {
@@ -40,6 +106,7 @@ impl Item {
             type_id,
             components: vec![],
             format_version: SemVer::current(),
+            menu_category: None,
         }
     }
 
@@ -56,6 +123,21 @@ impl Item {
         sc
     }
 
+    /// The tags of the item's `minecraft:tags` component.
+    pub fn tags(&self) -> Vec<Tag> {
+        self.components
+            .iter()
+            .filter(|component| component.id() == "minecraft:tags")
+            .flat_map(|component| match &component.value()["tags"] {
+                Value::Array(tags) => tags
+                    .iter()
+                    .filter_map(|tag| tag.as_str().map(Tag::new))
+                    .collect(),
+                _ => vec![],
+            })
+            .collect()
+    }
+
     pub fn type_id(&self) -> Identifier {
         self.type_id.clone()
     }
@@ -75,14 +157,16 @@ impl Item {
 
         cloned_self
     }
-}
 
-#[derive(Template)]
-#[template(path = "item_serialization/item_template.json.jinja2", escape = "none")]
-struct ItemTemplate<'a> {
-    pub id: &'a str,
-    pub components: Vec<String>,
-    pub format_version: String,
+    /// Places the item in the creative inventory. Replaces the deprecated
+    /// `ItemCreativeCategoryComponent`.
+    pub fn using_menu_category(&self, menu_category: MenuCategory) -> Self {
+        let mut cloned_self = self.clone();
+
+        cloned_self.menu_category = Some(menu_category);
+
+        cloned_self
+    }
 }
 
 #[derive(Template)]
@@ -0,0 +1,300 @@
+use crate::image::{Image, Recolor};
+use crate::item::component::{
+    ItemDamageComponent, ItemDiggerComponent, ItemDurabiltyComponent, ItemEnchantableComponent,
+    ItemHandEquippedComponent, ItemIconComponent, ItemMaxStackSizeComponent,
+    ItemRepairableComponent, ItemTagsComponent,
+};
+use crate::item::item_registry::ItemTexture;
+use crate::item::utils::{capitalized, EnchantableSlot, ItemRepairEntry, ItemTextureDescriptor};
+use crate::item::Item;
+use crate::localization::Localization;
+use crate::pack::Pack;
+use crate::recipe::{RecipeIO, ShapedRecipe};
+use crate::tag::TagQuery;
+use crate::vio::{Buildable, Identifier, RangeDescriptor};
+
+/// Template textures the tools are recolored from.
+#[derive(Clone)]
+pub struct ToolTemplates {
+    pub sword: Image,
+    pub pickaxe: Image,
+    pub axe: Image,
+    pub shovel: Image,
+    pub hoe: Image,
+}
+
+impl ToolTemplates {
+    pub fn new(sword: Image, pickaxe: Image, axe: Image, shovel: Image, hoe: Image) -> Self {
+        Self {
+            sword,
+            pickaxe,
+            axe,
+            shovel,
+            hoe,
+        }
+    }
+}
+
+/// The material tier of a tool set.
+#[derive(Clone)]
+pub struct ToolTier {
+    /// Damage of the sword. Other tools deal less, like in vanilla.
+    pub damage: i32,
+    /// Mining speed on blocks the tool is made for.
+    pub speed: i32,
+    pub durability: i32,
+    pub enchantability: u8,
+    pub repair_item: Identifier,
+    pub recolor: Recolor,
+}
+
+impl ToolTier {
+    pub fn new(
+        damage: i32,
+        speed: i32,
+        durability: i32,
+        enchantability: u8,
+        repair_item: Identifier,
+    ) -> Self {
+        Self {
+            damage,
+            speed,
+            durability,
+            enchantability,
+            repair_item,
+            recolor: Recolor::None,
+        }
+    }
+
+    pub fn using_recolor(self, recolor: Recolor) -> Self {
+        Self { recolor, ..self }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToolKind {
+    Sword,
+    Pickaxe,
+    Axe,
+    Shovel,
+    Hoe,
+}
+
+impl ToolKind {
+    pub fn all() -> Vec<ToolKind> {
+        vec![
+            ToolKind::Sword,
+            ToolKind::Pickaxe,
+            ToolKind::Axe,
+            ToolKind::Shovel,
+            ToolKind::Hoe,
+        ]
+    }
+
+    pub fn str_kind(&self) -> &'static str {
+        match self {
+            ToolKind::Sword => "sword",
+            ToolKind::Pickaxe => "pickaxe",
+            ToolKind::Axe => "axe",
+            ToolKind::Shovel => "shovel",
+            ToolKind::Hoe => "hoe",
+        }
+    }
+
+    /// The vanilla block tags this kind of tool mines faster.
+    pub fn mining_tags(&self) -> Vec<&'static str> {
+        match self {
+            ToolKind::Sword => vec!["minecraft:is_sword_item_destructible"],
+            ToolKind::Pickaxe => vec![
+                "stone",
+                "metal",
+                "rail",
+                "mob_spawner",
+                "minecraft:is_pickaxe_item_destructible",
+            ],
+            ToolKind::Axe => vec!["wood", "pumpkin", "plant", "minecraft:is_axe_item_destructible"],
+            ToolKind::Shovel => vec![
+                "dirt",
+                "sand",
+                "gravel",
+                "grass",
+                "snow",
+                "minecraft:is_shovel_item_destructible",
+            ],
+            ToolKind::Hoe => vec!["minecraft:is_hoe_item_destructible"],
+        }
+    }
+
+    pub fn enchantable_slot(&self) -> EnchantableSlot {
+        match self {
+            ToolKind::Sword => EnchantableSlot::Sword,
+            ToolKind::Pickaxe => EnchantableSlot::Pickaxe,
+            ToolKind::Axe => EnchantableSlot::Axe,
+            ToolKind::Shovel => EnchantableSlot::Shovel,
+            ToolKind::Hoe => EnchantableSlot::Hoe,
+        }
+    }
+
+    fn damage(&self, sword_damage: i32) -> i32 {
+        let damage = match self {
+            ToolKind::Sword => sword_damage,
+            ToolKind::Axe => sword_damage - 1,
+            ToolKind::Pickaxe => sword_damage - 2,
+            ToolKind::Shovel => sword_damage - 3,
+            ToolKind::Hoe => 1,
+        };
+
+        damage.max(1)
+    }
+
+    fn pattern(&self) -> Vec<&'static str> {
+        match self {
+            ToolKind::Sword => vec!["X", "X", "S"],
+            ToolKind::Pickaxe => vec!["XXX", " S ", " S "],
+            ToolKind::Axe => vec!["XX", "XS", " S"],
+            ToolKind::Shovel => vec!["X", "S", "S"],
+            ToolKind::Hoe => vec!["XX", " S", " S"],
+        }
+    }
+}
+
+/// Generates a sword, pickaxe, axe, shovel and hoe of a material: items with
+/// vanilla mining tags, icons, crafting recipes and names.
+#[derive(Clone)]
+pub struct ToolSet {
+    pub namespace: String,
+    pub material: String,
+    pub display_name: String,
+    pub templates: ToolTemplates,
+    pub tier: ToolTier,
+}
+
+impl ToolSet {
+    pub fn new(
+        namespace: impl Into<String>,
+        material: impl Into<String>,
+        display_name: impl Into<String>,
+        templates: ToolTemplates,
+        tier: ToolTier,
+    ) -> Self {
+        Self {
+            namespace: namespace.into(),
+            material: material.into(),
+            display_name: display_name.into(),
+            templates,
+            tier,
+        }
+    }
+
+    pub fn tool_id(&self, kind: ToolKind) -> Identifier {
+        Identifier::new(&self.namespace, format!("{}_{}", self.material, kind.str_kind()))
+    }
+
+    fn texture_id(&self, kind: ToolKind) -> String {
+        format!("{}_{}_{}", self.namespace, self.material, kind.str_kind())
+    }
+
+    fn template(&self, kind: ToolKind) -> &Image {
+        match kind {
+            ToolKind::Sword => &self.templates.sword,
+            ToolKind::Pickaxe => &self.templates.pickaxe,
+            ToolKind::Axe => &self.templates.axe,
+            ToolKind::Shovel => &self.templates.shovel,
+            ToolKind::Hoe => &self.templates.hoe,
+        }
+    }
+
+    fn digger(&self, kind: ToolKind) -> ItemDiggerComponent {
+        ItemDiggerComponent::for_tags(&TagQuery::any(kind.mining_tags()), self.tier.speed)
+    }
+
+    pub fn item(&self, kind: ToolKind) -> Item {
+        Item::new(self.tool_id(kind)).using_components(vec![
+            ItemIconComponent::new(ItemTextureDescriptor::new(self.texture_id(kind))).build(),
+            ItemMaxStackSizeComponent::new(1).build(),
+            ItemHandEquippedComponent::new(true).build(),
+            ItemDamageComponent::new(kind.damage(self.tier.damage)).build(),
+            self.digger(kind).build(),
+            ItemDurabiltyComponent::new(RangeDescriptor::new(100, 100), self.tier.durability)
+                .build(),
+            ItemEnchantableComponent::new(self.tier.enchantability, kind.enchantable_slot())
+                .build(),
+            ItemRepairableComponent::new(vec![ItemRepairEntry::new(
+                vec![self.tier.repair_item.render()],
+                "q.max_durability * 0.25",
+            )])
+            .build(),
+            ItemTagsComponent::new(vec![
+                format!("minecraft:is_{}", kind.str_kind()),
+                "minecraft:is_tool".to_string(),
+            ])
+            .build(),
+        ])
+    }
+
+    /// The generated items, in sword, pickaxe, axe, shovel, hoe order.
+    pub fn items(&self) -> Vec<Item> {
+        ToolKind::all()
+            .into_iter()
+            .map(|kind| self.item(kind))
+            .collect()
+    }
+
+    pub fn icons(&self) -> Vec<ItemTexture> {
+        ToolKind::all()
+            .into_iter()
+            .map(|kind| {
+                ItemTexture::new(
+                    self.texture_id(kind),
+                    format!("{}_{}", self.material, kind.str_kind()),
+                    self.tier.recolor.apply(self.template(kind)),
+                )
+            })
+            .collect()
+    }
+
+    pub fn recipes(&self) -> Vec<ShapedRecipe> {
+        ToolKind::all()
+            .into_iter()
+            .map(|kind| {
+                let id = self.tool_id(kind);
+
+                ShapedRecipe::new(
+                    Identifier::new(&self.namespace, format!("{}_recipe", id.value)),
+                    RecipeIO::new_typed(id).using_count(1),
+                )
+                .using_ingredients(vec![
+                    RecipeIO::new_typed(self.tier.repair_item.clone()).using_key('X'),
+                    RecipeIO::new_typed(Identifier::new("minecraft", "stick")).using_key('S'),
+                ])
+                .using_pattern(kind.pattern())
+                .using_tags(vec!["crafting_table"])
+            })
+            .collect()
+    }
+
+    pub fn add_names(&self, localization: &mut Localization) {
+        for kind in ToolKind::all() {
+            localization.add_item_name(
+                self.tool_id(kind),
+                format!("{} {}", self.display_name, capitalized(kind.str_kind())),
+            );
+        }
+    }
+
+    /// Registers the tools, their icons and recipes in the pack and adds the
+    /// item names to the given localization.
+    pub fn register(&self, pack: &mut Pack, localization: &mut Localization) {
+        for icon in self.icons() {
+            pack.register_item_texture(icon);
+        }
+        for item in self.items() {
+            pack.register_item(item);
+        }
+        for recipe in self.recipes() {
+            pack.register_recipe(recipe.build());
+        }
+
+        self.add_names(localization);
+    }
+}
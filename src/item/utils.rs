@@ -1,9 +1,10 @@
 use crate::vio::Identifier;
 use crate::vio::ViolaDefault;
-use serde::{Deserialize, Serialize, Serializer};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use viola::ViolaDefault;
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[derive(Default)]
 pub enum EnchantableSlot {
@@ -28,7 +29,7 @@ pub enum EnchantableSlot {
     All,
 }
 
-#[derive(Clone, Debug, Serialize, Default, ViolaDefault)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, ViolaDefault)]
 pub struct DurabilityThreshold {
     durability: i32,
     sound_effect: Option<String>,
@@ -61,7 +62,7 @@ impl DurabilityThreshold {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Default, ViolaDefault)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, ViolaDefault)]
 pub struct ItemTextureDescriptor {
     pub default: String,
     pub dyed: Option<String>,
@@ -106,7 +107,7 @@ impl ItemRepairEntry {
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[derive(Default)]
 pub enum ItemRarity {
@@ -117,7 +118,7 @@ pub enum ItemRarity {
     Epic,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[derive(Default)]
 pub enum ItemAnimation {
@@ -164,3 +165,137 @@ impl Serialize for ItemWearableSlot {
         serializer.serialize_str(&self.str_slot())
     }
 }
+
+impl<'de> Deserialize<'de> for ItemWearableSlot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let slot = String::deserialize(deserializer)?;
+
+        [
+            ItemWearableSlot::WeaponOffhand,
+            ItemWearableSlot::ArmorHead,
+            ItemWearableSlot::ArmorChest,
+            ItemWearableSlot::ArmorLegs,
+            ItemWearableSlot::ArmorFeet,
+        ]
+        .into_iter()
+        .find(|candidate| candidate.str_slot() == slot)
+        .ok_or_else(|| D::Error::custom(format!("unknown wearable slot \"{}\"", slot)))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default, ViolaDefault)]
+pub struct ShooterAmmunition {
+    pub item: Identifier,
+    pub use_offhand: bool,
+    pub search_inventory: bool,
+    pub use_in_creative: bool,
+}
+
+impl ShooterAmmunition {
+    pub fn new(item: Identifier) -> Self {
+        Self {
+            item,
+            use_offhand: false,
+            search_inventory: true,
+            use_in_creative: false,
+        }
+    }
+
+    pub fn using_use_offhand(self, use_offhand: bool) -> Self {
+        Self {
+            use_offhand,
+            ..self
+        }
+    }
+
+    pub fn using_search_inventory(self, search_inventory: bool) -> Self {
+        Self {
+            search_inventory,
+            ..self
+        }
+    }
+
+    pub fn using_use_in_creative(self, use_in_creative: bool) -> Self {
+        Self {
+            use_in_creative,
+            ..self
+        }
+    }
+}
+
+/// Conditions of a kinetic weapon's damage, knockback or dismount effect.
+#[derive(Clone, Debug, Serialize, Deserialize, Default, ViolaDefault)]
+pub struct KineticWeaponConditions {
+    pub max_duration: i32,
+    pub min_speed: f64,
+    pub min_relative_speed: f64,
+}
+
+impl KineticWeaponConditions {
+    pub fn new(max_duration: i32, min_speed: f64, min_relative_speed: f64) -> Self {
+        Self {
+            max_duration,
+            min_speed,
+            min_relative_speed,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum MenuCategoryName {
+    Construction,
+    Equipment,
+    #[default]
+    Items,
+    Nature,
+    None,
+}
+
+/// The creative inventory placement of an item or block.
+#[derive(Clone, Debug, Serialize, Deserialize, Default, ViolaDefault)]
+pub struct MenuCategory {
+    pub category: MenuCategoryName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_hidden_in_commands: Option<bool>,
+}
+
+impl MenuCategory {
+    pub fn new(category: MenuCategoryName) -> Self {
+        Self {
+            category,
+            group: None,
+            is_hidden_in_commands: None,
+        }
+    }
+
+    pub fn using_group(self, group: impl Into<String>) -> Self {
+        Self {
+            group: Some(group.into()),
+            ..self
+        }
+    }
+
+    pub fn using_is_hidden_in_commands(self, is_hidden_in_commands: bool) -> Self {
+        Self {
+            is_hidden_in_commands: Some(is_hidden_in_commands),
+            ..self
+        }
+    }
+}
+
+/// Uppercases the first letter, used to derive display names of generated items.
+pub(crate) fn capitalized(src: &str) -> String {
+    let mut chars = src.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
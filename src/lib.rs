@@ -1,14 +1,24 @@
+pub mod asset;
+pub mod biome;
 pub mod block;
+pub mod camera;
+pub mod codegen;
 pub mod constant;
+pub mod feature;
+pub mod fog;
 pub mod image;
+mod import;
 pub mod item;
 pub mod localization;
 pub mod logger;
 pub mod pack;
 pub mod recipe;
+pub mod report;
 pub mod template;
+pub mod texture_set;
 pub mod vio;
 pub mod script;
+pub mod tag;
 pub mod vio_vanilla;
 
 pub use viola::viola;
@@ -16,22 +26,67 @@ pub use viola::viola;
 #[cfg(test)]
 mod tests {
     // use std::collections::HashMap;
+    use crate::asset::AssetSource;
     use crate::block::block_registry::{
-        BlockRegistry, BlockTexture, Faces, PerFaceBlockAtlasEntry,
+        AllBlockAtlasEntry, BlockRegistry, BlockTexture, BlockTextureIssue, Faces,
+        FlipbookSource, FlipbookTexture, PerFaceBlockAtlasEntry,
     };
     use crate::block::component::{
         BlockCollisionBoxComponent, BlockDisplayNameComponent, BlockFrictionComponent,
-        BlockPlacementFilterComponent,
+        BlockGeometryComponent, BlockMaterialInstancesComponent, BlockPlacementFilterComponent,
+        BlockTagComponent,
+    };
+    use crate::block::component::{
+        BlockComponent, BlockCraftingTableComponent, BlockDestructionParticlesComponent,
+        BlockItemVisualComponent, BlockLiquidDetectionComponent, BlockMovableComponent,
+        BlockPrecipitationInteractionsComponent, BlockRandomOffsetComponent,
+        BlockRedstoneProducerComponent, BlockReplaceableComponent,
+    };
+    use crate::block::building_set::{BlockPreset, BuildingSet};
+    use crate::block::condition::{BlockCondition, BlockConditionError};
+    use crate::block::crafting_station::CraftingStation;
+    use crate::block::culling::{
+        BlockCullingIssue, BlockCullingRules, CullingCondition, CullingRule,
+    };
+    use crate::block::permutation::BlockPermutation;
+    use crate::block::state::{BoolBlockState, NumericBlockState, RangedBlockState};
+    use crate::block::traits::{
+        BlockRawTrait, ConnectionState, ConnectionTrait, PlacementDirectionState,
+        PlacementDirectionTrait, PlacementPositionState, PlacementPositionTrait,
     };
-    use crate::block::state::NumericBlockState;
     use crate::block::utils::{
         BlockDescriptor, BlockDestroySpeed, BlockFace, BlockPlacementCondition,
+        BlockRenderMethod, MaterialInstance,
+    };
+    use crate::block::utils::{
+        BlockMovementType, BlockStickyType, LiquidDetectionRule, LiquidReaction,
+        ParticleTintMethod, PrecipitationBehavior, RandomOffsetAxis,
+    };
+    use crate::biome::{
+        serialize_client_biomes, Biome, BiomeClimate, BiomeClimateCategory,
+        BiomeOverworldGenerationRules, BiomeSurfaceParameters, ClientBiome,
     };
     use crate::block::Block;
-    use crate::image::Image;
+    use crate::camera::{CameraListener, CameraPreset};
+    use crate::fog::{
+        DistanceFog, FogMedium, FogRenderDistanceType, FogSetting, MediaCoefficient,
+        VolumetricDensity,
+    };
+    use crate::feature::rule::{BiomeFilter, FeatureDistribution, FeatureRule, PlacementPass};
+    use crate::feature::{CoordinateRange, Feature, OreFeature, OreReplaceRule};
+    use crate::image::{Channel, Image, Recolor};
+    use crate::item::armor_set::{ArmorProtection, ArmorSet, ArmorTemplates};
+    use crate::item::attachable::{Attachable, AttachableScripts, AttachableTexture};
+    use crate::item::catalog::{serialize_crafting_item_catalog, CreativeGroup};
+    use crate::item::food::FoodItem;
+    use crate::item::tool_set::{ToolKind, ToolSet, ToolTemplates, ToolTier};
     use crate::item::component::{
         ItemAllowOffHandComponent, ItemCustomComponentsComponent, ItemHandEquippedComponent,
-        ItemMaxStackSizeComponent,
+        ItemMaxStackSizeComponent, ItemTagsComponent,
+    };
+    use crate::item::component::{
+        ItemComponent, ItemCompostableComponent, ItemKineticWeaponComponent,
+        ItemPiercingWeaponComponent, ItemShooterComponent, ItemSwingDurationComponent,
     };
     use crate::item::component::{
         ItemDamageAbsorptionComponent, ItemDiggerComponent, ItemDurabiltyComponent,
@@ -39,23 +94,32 @@ mod tests {
     };
     use crate::item::item_registry::ItemTexture;
     use crate::item::utils::ItemTextureDescriptor;
-    use crate::item::utils::{ItemRepairEntry};
+    use crate::item::utils::{ItemRepairEntry, ItemWearableSlot, MenuCategory, MenuCategoryName};
+    use crate::item::utils::{KineticWeaponConditions, ShooterAmmunition};
     use crate::localization::Localization;
-    use crate::recipe::{FurnaceRecipe, RecipeIO, ShapedRecipe, ShapelessRecipe};
+    use crate::report::{BuildReport, PermutationLimits};
+    use crate::tag::{Tag, TagQuery, TagRegistry, UndefinedTag};
+    use crate::texture_set::{MerLayer, TextureSet, TextureSetColor};
+    use crate::recipe::{
+        recipe_from_json, validate_recipe_tags, FurnaceRecipe, Recipe, RecipeIO, ShapedRecipe,
+        ShapelessRecipe, UnknownRecipeTag,
+    };
     use crate::vio::ViolaDefault;
     use crate::vio::{
-        Buildable, Generatable, Identifier, MolangStatement, RangeDescriptor, SemVer, VecInto,
+        json_equivalent, Buildable, Generatable, Identifier, MolangStatement, RangeDescriptor,
+        RGBColor, SemVer, Vec3, VecInto,
     };
     use crate::{
+        codegen,
         item::{
             component::{ItemDamageComponent, ItemDisplayNameComponent, ItemIconComponent},
             Item,
         },
-        pack::Pack,
+        pack::{Pack, PackPart},
         script::ScriptData
     };
     use viola::viola;
-    use crate::vio_vanilla::script_additions::{CustomCommand, CustomCommandArg};
+    use crate::vio_vanilla::script_additions::{ConsumeEffect, CustomCommand, CustomCommandArg};
 
     fn register_items(pack: &mut Pack) {
         pack.register_item_texture(ItemTexture::new(
@@ -292,6 +356,8 @@ mod tests {
             blocks: vec![],
             terrain_atlas: vec![],
             textures: vec![],
+            flipbooks: vec![],
+            culling_rules: vec![],
         };
 
         let block = Block::new(Identifier::new("hello", "world"))
@@ -318,4 +384,1720 @@ mod tests {
         // }
 
     }
+
+    #[test]
+    fn structural_serialization() {
+        let empty: serde_json::Value =
+            serde_json::from_str(&Item::new(Identifier::new("test", "empty")).serialize()).unwrap();
+        assert_eq!(empty["minecraft:item"]["components"], serde_json::json!({}));
+
+        let duplicated = Block::new(Identifier::new("test", "duplicated")).using_components(vec![
+            BlockFrictionComponent::new(0.4).build(),
+            BlockDisplayNameComponent::new("Duplicated").build(),
+            BlockFrictionComponent::new(0.6).build(),
+        ]);
+        let err = duplicated.to_value().unwrap_err();
+        assert_eq!(err.component, "minecraft:friction");
+
+        let mut item = Item::new(Identifier::new("test", "ordered")).using_components(vec![
+            ItemMaxStackSizeComponent::new(16).build(),
+            ItemDamageComponent::new(4).build(),
+        ]);
+        let reordered = item.using_components(vec![
+            ItemDamageComponent::new(4).build(),
+            ItemMaxStackSizeComponent::new(16).build(),
+        ]);
+        assert_eq!(item.serialize(), reordered.serialize());
+    }
+
+    #[test]
+    fn import_json() {
+        let item_src = r#"{
+            "format_version": "1.21.40",
+            "minecraft:item": {
+                "description": { "identifier": "legacy:ruby" },
+                "components": {
+                    "minecraft:damage": { "value": 5 },
+                    "minecraft:icon": { "textures": { "default": "ruby" } },
+                    "legacy:sparkle": { "interval": 20 }
+                }
+            }
+        }"#;
+        let item = Item::from_json(item_src).unwrap();
+        let original: serde_json::Value = serde_json::from_str(item_src).unwrap();
+        assert_eq!(item.type_id().render(), "legacy:ruby");
+        assert!(json_equivalent(&item.to_value().unwrap(), &original));
+
+        let block = Block::from_json(
+            r#"{
+                "format_version": "1.21.40",
+                "minecraft:block": {
+                    "description": {
+                        "identifier": "legacy:lamp",
+                        "states": { "legacy:lit": [false, true] },
+                        "traits": {
+                            "minecraft:placement_direction": {
+                                "enabled_states": ["minecraft:cardinal_direction"]
+                            }
+                        }
+                    },
+                    "components": { "minecraft:light_emission": 0 },
+                    "permutations": [{
+                        "condition": "q.block_state('legacy:lit')",
+                        "components": { "minecraft:light_emission": 15 }
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+        let block_json = block.to_value().unwrap();
+        assert_eq!(
+            block_json["minecraft:block"]["permutations"][0]["components"]
+                ["minecraft:light_emission"],
+            15
+        );
+        assert!(block_json["minecraft:block"]["description"]["states"]["legacy:lit"].is_array());
+        assert_eq!(block.trait_states()[0].id().render(), "minecraft:cardinal_direction");
+
+        let recipe = recipe_from_json(
+            r#"{
+                "format_version": "1.12",
+                "minecraft:recipe_shaped": {
+                    "description": { "identifier": "legacy:ruby_block" },
+                    "tags": ["crafting_table"],
+                    "pattern": ["XXX", "XXX", "XXX"],
+                    "key": { "X": { "item": "legacy:ruby" } },
+                    "result": { "item": "legacy:ruby_block", "count": 1 }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(recipe.id().render(), "legacy:ruby_block");
+    }
+
+    #[test]
+    fn import_pack() {
+        let root = std::env::temp_dir().join("violin_import_test");
+        let _ = std::fs::remove_dir_all(&root);
+        let bp = root.join("legacy_BP");
+        let rp = root.join("legacy_RP");
+        for dir in [bp.join("items"), bp.join("ui"), rp.join("texts"), rp.join("textures/items")] {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+
+        let manifest = |uuid: &str, module: &str| {
+            serde_json::json!({
+                "format_version": 2,
+                "header": {
+                    "name": "Legacy", "description": "", "uuid": uuid, "version": [1, 2, 3]
+                },
+                "modules": [{ "type": "data", "uuid": module, "version": [1, 0, 0] }]
+            })
+            .to_string()
+        };
+        std::fs::write(bp.join("manifest.json"), manifest("bp-uuid", "bp-module")).unwrap();
+        std::fs::write(rp.join("manifest.json"), manifest("rp-uuid", "rp-module")).unwrap();
+        std::fs::write(
+            bp.join("items/ruby.json"),
+            r#"{
+                "format_version": "1.21.40",
+                "minecraft:item": {
+                    "description": { "identifier": "legacy:ruby" },
+                    "components": { "minecraft:icon": { "textures": { "default": "ruby" } } }
+                }
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(bp.join("ui/custom.json"), "{}").unwrap();
+        std::fs::write(
+            rp.join("textures/item_texture.json"),
+            r#"{ "texture_data": { "ruby": { "textures": "textures/items/ruby" } } }"#,
+        )
+        .unwrap();
+        Image::default().build(rp.join("textures/items/ruby.png"));
+        std::fs::write(rp.join("texts/en_US.lang"), "item.legacy:ruby.name=Ruby\n").unwrap();
+
+        let mut pack = Pack::import(&bp, &rp).unwrap();
+        pack.generate();
+
+        let out = std::path::PathBuf::from("./violin_output/packs/legacy");
+        let bp_manifest: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(out.join("BP/manifest.json")).unwrap())
+                .unwrap();
+        assert_eq!(bp_manifest["header"]["uuid"], "bp-uuid");
+        assert!(out.join("BP/items/legacy_ruby.item.json").exists());
+        assert!(out.join("BP/ui/custom.json").exists());
+        assert!(out.join("RP/textures/items/ruby.png").exists());
+        assert!(std::fs::read_to_string(out.join("RP/texts/en_US.lang"))
+            .unwrap()
+            .contains("item.legacy:ruby.name=Ruby"));
+    }
+
+    #[test]
+    fn block_traits() {
+        let block = Block::new(Identifier::new("violin", "pillar")).using_traits(vec![
+            PlacementDirectionTrait::new(vec![PlacementDirectionState::CardinalDirection])
+                .using_y_rotation_offset(180.0)
+                .build(),
+            PlacementPositionTrait::new(vec![PlacementPositionState::VerticalHalf]).build(),
+            ConnectionTrait::new(vec![ConnectionState::CardinalConnections]).build(),
+        ]);
+
+        let value = block.to_value().unwrap();
+        let traits = &value["minecraft:block"]["description"]["traits"];
+        assert_eq!(
+            traits["minecraft:placement_direction"],
+            serde_json::json!({
+                "enabled_states": ["minecraft:cardinal_direction"],
+                "y_rotation_offset": 180.0
+            })
+        );
+        assert_eq!(
+            traits["minecraft:placement_position"]["enabled_states"][0],
+            "minecraft:vertical_half"
+        );
+
+        let state_ids: Vec<String> = block
+            .trait_states()
+            .iter()
+            .map(|state| state.id().render())
+            .collect();
+        assert_eq!(state_ids[0], PlacementDirectionState::CardinalDirection.id().render());
+        assert_eq!(state_ids[1], "minecraft:vertical_half");
+        assert_eq!(state_ids.len(), 6);
+
+        let imported = Block::from_value(&value).unwrap();
+        assert!(json_equivalent(&imported.to_value().unwrap(), &value));
+    }
+
+    #[test]
+    fn permutation_conditions() {
+        let lit = BoolBlockState { id: Identifier::new("violin", "lit") }.build();
+        let power = RangedBlockState::new(Identifier::new("violin", "power"), 0, 3).build();
+        let facing = PlacementDirectionState::CardinalDirection.state();
+
+        let condition = BlockCondition::and(vec![
+            BlockCondition::equals(lit.as_ref(), true),
+            !BlockCondition::in_set(power.as_ref(), vec![0, 1]),
+            BlockCondition::equals(facing.as_ref(), "north"),
+        ]);
+        assert_eq!(
+            condition.render(),
+            "(q.block_state('violin:lit') == true && \
+             !((q.block_state('violin:power') == 0 || q.block_state('violin:power') == 1)) && \
+             q.block_state('minecraft:cardinal_direction') == 'north')"
+        );
+        assert_eq!(
+            (!BlockCondition::in_set(power.as_ref(), vec![1])).render(),
+            "!(q.block_state('violin:power') == 1)"
+        );
+        assert_eq!(
+            (!BlockCondition::and(vec![BlockCondition::equals(power.as_ref(), 1)])).render(),
+            "!(q.block_state('violin:power') == 1)"
+        );
+        let empty = BlockCondition::in_set(power.as_ref(), Vec::<i32>::new());
+        assert_eq!(empty.render(), "false");
+        assert_eq!((!empty).render(), "!(false)");
+
+        let mut block = Block::new(Identifier::new("violin", "lamp"))
+            .using_states(vec![lit.clone(), power.clone()])
+            .using_traits(vec![PlacementDirectionTrait::new(vec![
+                PlacementDirectionState::CardinalDirection,
+            ])
+            .build()]);
+        let valid = block.using_permutations(vec![
+            BlockPermutation::new_typed(condition, vec![]),
+            BlockPermutation::new("q.block_state('violin:lit') == false", vec![]),
+        ]);
+        assert!(valid.validate().is_ok());
+
+        let invalid = block.using_permutations(vec![
+            BlockPermutation::new_typed(BlockCondition::equals(power.as_ref(), 7), vec![]),
+            BlockPermutation::new("query.block_state(\"violin:missing\")", vec![]),
+        ]);
+        let errors = invalid.validate().unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            &errors[0],
+            BlockConditionError::ImpossibleValue { state, .. } if state == "violin:power"
+        ));
+        assert!(matches!(
+            &errors[1],
+            BlockConditionError::UndeclaredState { state, .. } if state == "violin:missing"
+        ));
+
+        let mut raw = Block::new(Identifier::new("violin", "lamp"))
+            .using_traits(vec![BlockRawTrait::new(
+                "minecraft:multi_block",
+                serde_json::json!({ "enabled_states": ["minecraft:multi_block_part"] }),
+            )
+            .build()])
+            .using_permutations(vec![BlockPermutation::new(
+                "q.block_state('minecraft:multi_block_part') == 0",
+                vec![],
+            )]);
+        assert!(raw.validate().is_ok());
+        let errors = raw
+            .using_permutations(vec![BlockPermutation::new(
+                "q.block_state('violin:missing') == 0",
+                vec![],
+            )])
+            .validate()
+            .unwrap_err();
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn permutation_analysis() {
+        let lit = BoolBlockState { id: Identifier::new("violin", "lit") }.build();
+        let power = RangedBlockState::new(Identifier::new("violin", "power"), 0, 15).build();
+
+        let block = Block::new(Identifier::new("violin", "lamp"))
+            .using_states(vec![lit.clone(), power.clone()])
+            .using_traits(vec![PlacementDirectionTrait::new(vec![
+                PlacementDirectionState::CardinalDirection,
+            ])
+            .build()])
+            .using_permutations(vec![
+                BlockPermutation::new_typed(BlockCondition::equals(lit.as_ref(), true), vec![]),
+                BlockPermutation::new_typed(
+                    BlockCondition::and(vec![
+                        BlockCondition::equals(power.as_ref(), 1),
+                        BlockCondition::equals(power.as_ref(), 2),
+                    ]),
+                    vec![],
+                ),
+            ]);
+        assert_eq!(block.state_combinations(), 2 * 16 * 4);
+
+        let report = BuildReport::new(&[block.clone(), block]);
+        assert_eq!(report.total_state_combinations, 2 * 2 * 16 * 4);
+        assert_eq!(report.blocks[0].permutations, 2);
+        assert_eq!(
+            report.blocks[0].unmatchable_permutations,
+            vec!["(q.block_state('violin:power') == 1 && q.block_state('violin:power') == 2)"]
+        );
+    }
+
+    #[test]
+    fn building_set() {
+        let set = BuildingSet::new(
+            "violin",
+            "marble",
+            "Marble",
+            Image::default(),
+            BlockPreset::all(),
+        );
+
+        let blocks = set.blocks();
+        assert_eq!(blocks.len(), 9);
+        for block in &blocks {
+            assert!(block.validate().is_ok());
+            assert!(block.unmatchable_permutations().is_empty());
+            block.to_value().unwrap();
+        }
+
+        let slab = set.block(BlockPreset::Slab).to_value().unwrap();
+        let permutations = slab["minecraft:block"]["permutations"].as_array().unwrap();
+        assert_eq!(
+            permutations[1]["condition"],
+            "q.block_state('violin:double') == true"
+        );
+        assert_eq!(
+            permutations[1]["components"]["minecraft:loot"],
+            "loot_tables/blocks/violin_marble_slab_double.json"
+        );
+        assert_eq!(
+            slab["minecraft:block"]["components"]["minecraft:custom_components"][0],
+            "violin:marble_slab"
+        );
+
+        let geometries = set.geometries();
+        assert_eq!(geometries.len(), 6);
+        assert_eq!(geometries[0].0, "models/blocks/violin_marble_slab.geo.json");
+        assert_eq!(
+            geometries[0].1["minecraft:geometry"][0]["description"]["identifier"],
+            "geometry.violin.marble_slab"
+        );
+        assert_eq!(set.loot_tables().len(), 1);
+        let recipes = set.recipes();
+        assert_eq!(recipes.len(), 7);
+        assert_eq!(recipes[0].pattern, vec!["XXX"]);
+        assert_eq!(recipes[3].ingredients.len(), 2);
+    }
+
+    #[test]
+    fn validate_block_textures() {
+        let used = BlockTexture::new(Image::default(), Identifier::new("violin", "used"), "used");
+        let unused =
+            BlockTexture::new(Image::default(), Identifier::new("violin", "unused"), "unused");
+
+        let mut registry = BlockRegistry::new();
+        registry.unified_add_texture(used.clone());
+        registry.unified_add_texture(unused);
+        let block = Block::new(Identifier::new("violin", "panel")).using_components(vec![
+            BlockMaterialInstancesComponent::new(std::collections::HashMap::from([
+                (
+                    BlockFace::Up,
+                    MaterialInstance::from_texture(&used, BlockRenderMethod::Opaque),
+                ),
+                (
+                    BlockFace::Down,
+                    MaterialInstance::new("violin:missing", BlockRenderMethod::Opaque),
+                ),
+                (
+                    BlockFace::North,
+                    MaterialInstance::new("stone", BlockRenderMethod::Opaque),
+                ),
+            ]))
+            .build(),
+        ]);
+        registry.add_block(block.clone());
+        registry.add_block(block);
+        registry.add_block_atlas_entry(
+            AllBlockAtlasEntry::new(Identifier::new("violin", "panel"), "violin:used", "stone")
+                .build(),
+        );
+
+        let issues = registry.validate_textures();
+        assert_eq!(issues.len(), 4);
+        assert!(issues.contains(&BlockTextureIssue::DanglingTexture {
+            owner: "block \"violin:panel\"".to_string(),
+            texture: "violin:missing".to_string(),
+        }));
+        assert!(issues.contains(&BlockTextureIssue::UnusedTexture {
+            texture: "violin:unused".to_string(),
+        }));
+        assert!(issues.contains(&BlockTextureIssue::DuplicateId {
+            kind: "block".to_string(),
+            id: "violin:panel".to_string(),
+        }));
+
+        let set = BuildingSet::new(
+            "violin",
+            "marble",
+            "Marble",
+            Image::default(),
+            vec![BlockPreset::Slab],
+        );
+        let slab = set.block(BlockPreset::Slab);
+        assert_eq!(slab.textures[0].id().render(), "violin:marble");
+        assert!(slab
+            .material_instance_textures()
+            .iter()
+            .all(|texture| texture == "violin:marble"));
+    }
+
+    #[test]
+    fn block_culling_rules() {
+        let culling = BlockCullingRules::new(Identifier::new("violin", "culling.slab"))
+            .using_rule(CullingRule::new(BlockFace::Down, "slab").using_face(BlockFace::Down))
+            .using_rule(
+                CullingRule::new(BlockFace::Up, "slab")
+                    .using_cube(0)
+                    .using_condition(CullingCondition::SameCullingLayer),
+            );
+        assert_eq!(culling.file_name(), "violin_culling.slab.json");
+        assert_eq!(
+            culling.value()["minecraft:block_culling_rules"]["rules"],
+            serde_json::json!([
+                {
+                    "direction": "down",
+                    "geometry_part": { "bone": "slab", "face": "down" }
+                },
+                {
+                    "direction": "up",
+                    "condition": "same_culling_layer",
+                    "geometry_part": { "bone": "slab", "cube": 0 }
+                }
+            ])
+        );
+
+        let geometry = |culling: &str| {
+            BlockGeometryComponent::new("geometry.slab", std::collections::HashMap::new())
+                .using_culling(Some(culling.to_string()))
+                .using_culling_layer(Some("violin:culling_layer.slab".to_string()))
+                .build()
+        };
+
+        let mut registry = BlockRegistry::new();
+        registry.culling_rules.push(culling);
+        registry.add_block(
+            Block::new(Identifier::new("violin", "slab"))
+                .using_components(vec![geometry("violin:culling.slab")]),
+        );
+        registry.add_block(
+            Block::new(Identifier::new("violin", "stairs"))
+                .using_components(vec![geometry("violin:culling.stairs")]),
+        );
+
+        assert_eq!(
+            registry.validate_culling_rules(),
+            vec![BlockCullingIssue::UnregisteredRules {
+                block: "violin:stairs".to_string(),
+                rules: "violin:culling.stairs".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn crafting_station() {
+        let station = CraftingStation::new(
+            Block::new(Identifier::new("violin", "fletching_table")),
+            "Fletching Table",
+        );
+        let block = station.block();
+        assert_eq!(block.crafting_tags(), vec!["violin:fletching_table"]);
+
+        let arrow = ShapelessRecipe::new(
+            Identifier::new("violin", "arrow"),
+            RecipeIO::new_typed(Identifier::new("minecraft", "arrow")),
+        )
+        .using_tags(vec!["crafting_table"])
+        .using_station(&station);
+        assert_eq!(arrow.tags, vec!["crafting_table", "violin:fletching_table"]);
+
+        let bolt = ShapedRecipe::new(
+            Identifier::new("violin", "bolt"),
+            RecipeIO::new_typed(Identifier::new("violin", "bolt")),
+        )
+        .using_tags(vec!["violin:crossbow_bench"]);
+        let recipes: Vec<std::sync::Arc<dyn Recipe>> = vec![arrow.build(), bolt.build()];
+
+        assert_eq!(validate_recipe_tags(&recipes, &[block]).len(), 1);
+        assert_eq!(
+            validate_recipe_tags(&recipes, &[]),
+            vec![
+                UnknownRecipeTag {
+                    recipe: "violin:arrow".to_string(),
+                    tag: "violin:fletching_table".to_string(),
+                },
+                UnknownRecipeTag {
+                    recipe: "violin:bolt".to_string(),
+                    tag: "violin:crossbow_bench".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn tag_registry() {
+        let mut tags = TagRegistry::new();
+        tags.tag_block(
+            Identifier::new("violin", "marble"),
+            vec![Tag::new("violin:marble"), Tag::new("stone")],
+        );
+        tags.tag_item(
+            Identifier::new("violin", "chisel"),
+            vec![Tag::new("violin:chisel")],
+        );
+
+        let query = TagQuery::any(vec!["violin:marble", "violin:granite", "stone"]);
+        assert_eq!(
+            query.render(),
+            "q.any_tag('violin:marble', 'violin:granite', 'stone')"
+        );
+        tags.block_descriptor(query);
+
+        let block = tags.apply_to_block(
+            &Block::new(Identifier::new("violin", "marble"))
+                .using_components(vec![BlockTagComponent::new("stone").build()]),
+        );
+        assert_eq!(block.tags(), vec![Tag::new("stone"), Tag::new("violin:marble")]);
+        assert_eq!(tags.apply_to_block(&block).components.len(), 2);
+
+        let item = tags.apply_to_item(
+            &Item::new(Identifier::new("violin", "chisel")).using_components(vec![
+                ItemTagsComponent::new(vec!["minecraft:is_tool".to_string()]).build(),
+            ]),
+        );
+        assert_eq!(
+            item.tags(),
+            vec![Tag::new("minecraft:is_tool"), Tag::new("violin:chisel")]
+        );
+
+        assert_eq!(
+            tags.validate(&[block]),
+            vec![UndefinedTag {
+                query: "q.any_tag('violin:marble', 'violin:granite', 'stone')".to_string(),
+                tag: Tag::new("violin:granite"),
+            }]
+        );
+    }
+
+    #[test]
+    fn flipbook_texture() {
+        let frames = vec![Image::default(), Image::default(), Image::default()];
+        let flipbook = FlipbookTexture::new(
+            Identifier::new("violin", "lava_lamp"),
+            "violin_lava_lamp",
+            FlipbookSource::Frames(frames),
+            4,
+        )
+        .unwrap()
+        .using_blend_frames(false)
+        .using_frames(vec![0, 1, 2, 1]);
+
+        assert_eq!(flipbook.texture.src().height(), 48);
+        assert_eq!(
+            flipbook.value(),
+            serde_json::json!({
+                "flipbook_texture": "textures/blocks/violin_lava_lamp",
+                "atlas_tile": "violin:lava_lamp",
+                "ticks_per_frame": 4,
+                "frames": [0, 1, 2, 1],
+                "blend_frames": false
+            })
+        );
+
+        assert!(FlipbookTexture::new(
+            Identifier::new("violin", "empty"),
+            "violin_empty",
+            FlipbookSource::Frames(vec![]),
+            4,
+        )
+        .is_none());
+
+        let large = Image::default().upscaled(2);
+        assert!(Image::vertical_strip(&[Image::default(), large.clone()]).is_none());
+        let strip = Image::vertical_strip(&[large.clone(), large]).unwrap();
+        assert!(Image::vertical_strip(std::slice::from_ref(&strip)).is_none());
+        assert!(FlipbookTexture::new(
+            Identifier::new("violin", "strip"),
+            "violin_strip",
+            FlipbookSource::Strip(strip),
+            4,
+        )
+        .is_some());
+        let uneven = std::env::temp_dir().join("violin_uneven_strip.png");
+        image::RgbaImage::new(16, 24).save(&uneven).unwrap();
+        assert!(FlipbookTexture::new(
+            Identifier::new("violin", "uneven"),
+            "violin_uneven",
+            FlipbookSource::Strip(Image::open(&uneven).unwrap()),
+            4,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn texture_set() {
+        let uniform = TextureSet::new("violin_marble", TextureSetColor::Registered).using_mer(
+            MerLayer::new(Channel::Value(0), Channel::Value(0), Channel::Value(200)),
+        );
+        assert!(uniform.images().unwrap().is_empty());
+        assert_eq!(
+            uniform.value(),
+            serde_json::json!({
+                "format_version": "1.16.100",
+                "minecraft:texture_set": {
+                    "color": "violin_marble",
+                    "metalness_emissive_roughness": [0, 0, 200]
+                }
+            })
+        );
+
+        let packed = TextureSet::new(
+            "violin_lamp",
+            TextureSetColor::Uniform(RGBColor::new(255, 128, 0)),
+        )
+        .using_mer(MerLayer::new_with_subsurface(
+            Channel::Value(0),
+            Channel::Image(Image::default()),
+            Channel::Value(128),
+            Channel::Value(64),
+        ))
+        .using_heightmap(Image::default());
+        let images = packed.images().unwrap();
+        let names: Vec<&str> = images.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["violin_lamp_mers", "violin_lamp_heightmap"]);
+        assert_eq!(images[0].1.width(), 16);
+        let texture_set = packed.value();
+        assert_eq!(texture_set["format_version"], "1.21.30");
+        assert_eq!(
+            texture_set["minecraft:texture_set"]["metalness_emissive_roughness_subsurface"],
+            "violin_lamp_mers"
+        );
+        assert_eq!(texture_set["minecraft:texture_set"]["heightmap"], "violin_lamp_heightmap");
+        assert!(texture_set["minecraft:texture_set"]["color"]
+            .as_str()
+            .unwrap()
+            .starts_with('#'));
+    }
+
+    #[test]
+    fn register_assets() {
+        let src = std::env::temp_dir().join("violin_asset_test");
+        let _ = std::fs::remove_dir_all(&src);
+        std::fs::create_dir_all(src.join("ui/nested")).unwrap();
+        std::fs::write(src.join("splashes.json"), r#"{ "splashes": [] }"#).unwrap();
+        std::fs::write(src.join("ui/hud_screen.json"), "{}").unwrap();
+        std::fs::write(src.join("ui/nested/panel.json"), "{}").unwrap();
+
+        let mut pack = Pack::new(
+            "Assets",
+            "asset_test",
+            "NaKeR",
+            SemVer::new(1, 0, 0),
+            "",
+            "./violin_output/dev/development_behavior_packs",
+            "./violin_output/dev/development_resource_packs",
+            Image::default(),
+            None,
+        );
+        pack.register_asset(
+            PackPart::RP,
+            "splashes.json",
+            AssetSource::File(src.join("splashes.json")),
+        );
+        pack.register_asset(PackPart::RP, "ui", AssetSource::Directory(src.join("ui")));
+        pack.register_asset(
+            PackPart::BP,
+            "notes/readme.txt",
+            AssetSource::Text("Hello".to_string()),
+        );
+        pack.register_asset(
+            PackPart::BP,
+            "notes/readme.txt",
+            AssetSource::Text("Hi".to_string()),
+        );
+        pack.register_asset(
+            PackPart::RP,
+            "materials/custom.material",
+            AssetSource::Json(serde_json::json!({ "materials": { "version": "1.0.0" } })),
+        );
+        pack.register_asset(
+            PackPart::BP,
+            "../outside.txt",
+            AssetSource::Text("No".to_string()),
+        );
+        pack.generate();
+
+        let out = std::path::PathBuf::from("./violin_output/packs/asset_test");
+        assert!(out.join("RP/splashes.json").exists());
+        assert!(out.join("RP/ui/hud_screen.json").exists());
+        assert!(out.join("RP/ui/nested/panel.json").exists());
+        assert_eq!(std::fs::read_to_string(out.join("BP/notes/readme.txt")).unwrap(), "Hi");
+        let material: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(out.join("RP/materials/custom.material")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(material["materials"]["version"], "1.0.0");
+        assert!(!out.join("outside.txt").exists());
+        assert_eq!(pack.assets().len(), 4);
+    }
+
+    #[test]
+    fn generate_source() {
+        let root = std::env::temp_dir().join("violin_codegen_test");
+        let _ = std::fs::remove_dir_all(&root);
+        let bp = root.join("legacy_BP");
+        let rp = root.join("legacy_RP");
+        for dir in [bp.join("items"), bp.join("blocks"), bp.join("recipes"), rp.clone()] {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+
+        let manifest = r#"{
+            "format_version": 2,
+            "header": { "name": "Legacy", "uuid": "uuid", "version": [1, 0, 0] },
+            "modules": [{ "type": "data", "uuid": "module", "version": [1, 0, 0] }]
+        }"#;
+        std::fs::write(bp.join("manifest.json"), manifest).unwrap();
+        std::fs::write(rp.join("manifest.json"), manifest).unwrap();
+        std::fs::write(
+            bp.join("items/ruby.json"),
+            r#"{
+                "format_version": "1.21.40",
+                "minecraft:item": {
+                    "description": {
+                        "identifier": "legacy:ruby",
+                        "menu_category": { "category": "items" }
+                    },
+                    "components": {
+                        "minecraft:damage": { "value": 5 },
+                        "minecraft:icon": { "textures": { "default": "ruby" } }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            bp.join("blocks/lamp.json"),
+            r#"{
+                "format_version": "1.21.40",
+                "minecraft:block": {
+                    "description": {
+                        "identifier": "legacy:lamp",
+                        "states": { "legacy:lit": [false, true] },
+                        "traits": {
+                            "minecraft:placement_direction": {
+                                "enabled_states": ["minecraft:cardinal_direction"]
+                            }
+                        }
+                    },
+                    "components": { "minecraft:light_emission": 0 },
+                    "permutations": [{
+                        "condition": "q.block_state('legacy:lit')",
+                        "components": { "minecraft:light_emission": 15 }
+                    }]
+                }
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            bp.join("recipes/lamp.json"),
+            r#"{
+                "format_version": "1.21.40",
+                "minecraft:recipe_shapeless": {
+                    "description": { "identifier": "legacy:lamp" },
+                    "tags": ["crafting_table"],
+                    "ingredients": [{ "item": "legacy:ruby", "count": 2 }],
+                    "result": "legacy:lamp"
+                }
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            rp.join("blocks.json"),
+            r#"{ "format_version": "1.1.0", "legacy:lamp": { "textures": "lamp", "sound": "glass" } }"#,
+        )
+        .unwrap();
+
+        let source = codegen::generate_pack_source(&bp, &rp).unwrap();
+
+        assert!(source.contains("use violin_rs::item::component::ItemRawComponent;"));
+        assert!(source.contains("ItemDamageComponent::new(5).build()"));
+        assert!(source.contains(".using_menu_category(MenuCategory::new(MenuCategoryName::Items))"));
+        assert!(source.contains("\"minecraft:icon\",\n"));
+        assert!(source.contains("BoolBlockState { id: Identifier::new(\"legacy\", \"lit\") }.build()"));
+        assert!(source.contains("BlockLightEmissionComponent::new(15).build()"));
+        assert!(source.contains(
+            "PlacementDirectionTrait::new(vec![PlacementDirectionState::CardinalDirection]).build()"
+        ));
+        assert!(source.contains("\"q.block_state('legacy:lit')\""));
+        assert!(source.contains("RecipeIO::new_typed(Identifier::new(\"legacy\", \"ruby\")).using_count(2)"));
+        assert!(source.contains(
+            "AllBlockAtlasEntry::new(Identifier::new(\"legacy\", \"lamp\"), \"lamp\", \"glass\")"
+        ));
+    }
+
+    #[test]
+    fn ore_feature() {
+        let ore = Block::new(Identifier::new("violin", "ruby_ore"));
+
+        let feature = OreFeature::new(
+            Identifier::new("violin", "ruby_ore_feature"),
+            6,
+            vec![OreReplaceRule::for_block(
+                &ore,
+                vec![Identifier::new("minecraft", "stone")],
+            )],
+        );
+        let rule = FeatureRule::new(
+            Identifier::new("violin", "ruby_ore_rule"),
+            feature.id(),
+            PlacementPass::UndergroundPass,
+            FeatureDistribution::new("8")
+                .using_x(CoordinateRange::uniform("0", "15"))
+                .using_y(CoordinateRange::uniform("-64", "16"))
+                .using_z(CoordinateRange::uniform("0", "15")),
+        )
+        .using_biome_filter(vec![BiomeFilter::has_biome_tag("overworld")]);
+
+        let feature_json: serde_json::Value = serde_json::from_str(&feature.serialize()).unwrap();
+        let rule_json: serde_json::Value = serde_json::from_str(&rule.serialize()).unwrap();
+
+        assert_eq!(
+            feature_json["minecraft:ore_feature"]["replace_rules"][0]["places_block"],
+            "violin:ruby_ore"
+        );
+        assert_eq!(
+            rule_json["minecraft:feature_rules"]["description"]["places_feature"],
+            "violin:ruby_ore_feature"
+        );
+        assert_eq!(
+            rule_json["minecraft:feature_rules"]["distribution"]["y"]["extent"][0],
+            "-64"
+        );
+    }
+
+    #[test]
+    fn armor_set() {
+        let set = ArmorSet::new(
+            "violin",
+            "ruby",
+            "Ruby",
+            ArmorTemplates::new(
+                Image::default(),
+                Image::default(),
+                Image::default(),
+                Image::default(),
+                Image::default(),
+                Image::default(),
+            ),
+            ArmorProtection::new(3, 8, 6, 3),
+            Identifier::new("violin", "ruby"),
+        );
+
+        let items = set.items();
+        let attachables = set.attachables();
+        let mut en_us = Localization::new("en_US");
+        set.add_names(&mut en_us);
+
+        assert_eq!(items.len(), 4);
+        assert_eq!(attachables.len(), 4);
+        assert_eq!(set.recipes().len(), 4);
+        assert_eq!(items[1].type_id().render(), "violin:ruby_chestplate");
+        assert_eq!(
+            en_us.item_names.get("violin:ruby_leggings").unwrap(),
+            "Ruby Leggings"
+        );
+
+        let leggings: serde_json::Value = serde_json::from_str(&attachables[2].serialize()).unwrap();
+        assert_eq!(
+            leggings["minecraft:attachable"]["description"]["geometry"]["default"],
+            "geometry.humanoid.armor.leggings"
+        );
+        assert_eq!(
+            leggings["minecraft:attachable"]["description"]["textures"]["default"],
+            "textures/models/armor/violin_ruby_layer_2"
+        );
+        let images: Vec<String> = attachables
+            .iter()
+            .flat_map(|attachable| attachable.images())
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(
+            images,
+            vec![
+                "textures/models/armor/violin_ruby_layer_1",
+                "textures/models/armor/violin_ruby_layer_2"
+            ]
+        );
+        let boots: serde_json::Value = serde_json::from_str(&attachables[3].serialize()).unwrap();
+        assert_eq!(
+            boots["minecraft:attachable"]["description"]["textures"]["default"],
+            "textures/models/armor/violin_ruby_layer_1"
+        );
+    }
+
+    #[test]
+    fn food_item() {
+        let food = FoodItem::new(Identifier::new("violin", "golden_berry"), 4, 1.2)
+            .using_can_always_eat(true)
+            .using_converts_to(Identifier::new("minecraft", "bowl"))
+            .using_use_modifiers(0.8, 0.5)
+            .using_effects(vec![
+                ConsumeEffect::new("regeneration", 100, 1),
+                ConsumeEffect::new("nausea", 200, 0).using_chance(0.3),
+            ]);
+
+        let item: serde_json::Value = serde_json::from_str(&food.item().serialize()).unwrap();
+        let components = &item["minecraft:item"]["components"];
+        assert_eq!(components["minecraft:food"]["nutrition"], 4);
+        assert_eq!(components["minecraft:food"]["saturation_modifier"], 1.2);
+        assert_eq!(components["minecraft:food"]["can_always_eat"], true);
+        assert_eq!(components["minecraft:food"]["using_converts_to"], "minecraft:bowl");
+        assert_eq!(components["minecraft:use_animation"]["value"], "eat");
+        assert_eq!(components["minecraft:use_modifiers"]["use_duration"], 0.8);
+        assert_eq!(components["minecraft:use_modifiers"]["movement_modifier"], 0.5);
+        assert_eq!(
+            components["minecraft:custom_components"],
+            serde_json::json!(["violin:golden_berry_effects"])
+        );
+
+        let script = food.script_addition().unwrap().script();
+        assert!(script.contains("registerCustomComponent(\"violin:golden_berry_effects\""));
+        assert!(script.contains(
+            "            source.addEffect(\"regeneration\", 100, { amplifier: 1 });"
+        ));
+        assert!(script.contains(
+            "            if (Math.random() < 0.3) source.addEffect(\"nausea\", 200, { amplifier: 0 });"
+        ));
+
+        let plain = FoodItem::new(Identifier::new("violin", "berry"), 2, 0.6);
+        assert!(plain.script_addition().is_none());
+        let plain: serde_json::Value = serde_json::from_str(&plain.item().serialize()).unwrap();
+        assert!(plain["minecraft:item"]["components"]
+            .get("minecraft:custom_components")
+            .is_none());
+    }
+
+    #[test]
+    fn tool_set() {
+        let white = Image::from_channels([
+            &Channel::Value(255),
+            &Channel::Value(255),
+            &Channel::Value(255),
+            &Channel::Image(Image::default()),
+        ])
+        .unwrap();
+        let set = ToolSet::new(
+            "violin",
+            "ruby",
+            "Ruby",
+            ToolTemplates::new(
+                white.clone(),
+                white.clone(),
+                white.clone(),
+                white.clone(),
+                white,
+            ),
+            ToolTier::new(7, 8, 1200, 14, Identifier::new("violin", "ruby"))
+                .using_recolor(Recolor::Tint(RGBColor::new(200, 100, 50))),
+        );
+
+        let items = set.items();
+        let mut en_us = Localization::new("en_US");
+        set.add_names(&mut en_us);
+
+        assert_eq!(items.len(), 5);
+        assert_eq!(items[3].type_id().render(), "violin:ruby_shovel");
+        assert_eq!(en_us.item_names.get("violin:ruby_pickaxe").unwrap(), "Ruby Pickaxe");
+
+        let components = |kind: ToolKind| -> serde_json::Value {
+            let item: serde_json::Value =
+                serde_json::from_str(&set.item(kind).serialize()).unwrap();
+            item["minecraft:item"]["components"].clone()
+        };
+        let damages: Vec<serde_json::Value> = ToolKind::all()
+            .into_iter()
+            .map(|kind| components(kind)["minecraft:damage"]["value"].clone())
+            .collect();
+        assert_eq!(damages, vec![7, 5, 6, 4, 1]);
+        let sword = components(ToolKind::Sword);
+        assert_eq!(sword["minecraft:durability"]["max_durability"], 1200);
+        assert_eq!(sword["minecraft:enchantable"]["value"], 14);
+        let axe_speed = &components(ToolKind::Axe)["minecraft:digger"]["destroy_speeds"][0];
+        assert_eq!(
+            axe_speed["block"]["tags"],
+            "q.any_tag('wood', 'pumpkin', 'plant', 'minecraft:is_axe_item_destructible')"
+        );
+        assert_eq!(axe_speed["speed"], 8);
+
+        let recipes = set.recipes();
+        assert_eq!(recipes.len(), 5);
+        let pickaxe: serde_json::Value = serde_json::from_str(&recipes[1].serialize()).unwrap();
+        let pickaxe = &pickaxe["minecraft:recipe_shaped"];
+        assert_eq!(pickaxe["description"]["identifier"], "violin:ruby_pickaxe_recipe");
+        assert_eq!(pickaxe["pattern"], serde_json::json!(["XXX", " S ", " S "]));
+        assert_eq!(pickaxe["key"]["X"]["item"], "violin:ruby");
+        assert_eq!(pickaxe["result"]["item"], "violin:ruby_pickaxe");
+
+        let icons = set.icons();
+        assert_eq!(icons[4].id, "violin_ruby_hoe");
+        assert_eq!(icons[4].file_name, "ruby_hoe");
+        assert_eq!(icons[4].src.pixel(0, 0), [200, 100, 50, 0]);
+    }
+
+    #[test]
+    fn creative_groups() {
+        let grouped_item = Item::new(Identifier::new("violin", "ruby")).using_menu_category(
+            MenuCategory::new(MenuCategoryName::Items).using_group("violin:gems"),
+        );
+        let hidden_item = Item::new(Identifier::new("violin", "debug_stick")).using_menu_category(
+            MenuCategory::new(MenuCategoryName::None).using_is_hidden_in_commands(true),
+        );
+        let grouped_block = Block::new(Identifier::new("violin", "ruby_block")).using_menu_category(
+            MenuCategory::new(MenuCategoryName::Construction).using_group("violin:gems"),
+        );
+
+        let item: serde_json::Value = serde_json::from_str(&grouped_item.serialize()).unwrap();
+        assert_eq!(
+            item["minecraft:item"]["description"]["menu_category"],
+            serde_json::json!({ "category": "items", "group": "violin:gems" })
+        );
+        let item: serde_json::Value = serde_json::from_str(&hidden_item.serialize()).unwrap();
+        assert_eq!(
+            item["minecraft:item"]["description"]["menu_category"],
+            serde_json::json!({ "category": "none", "is_hidden_in_commands": true })
+        );
+        let block: serde_json::Value = serde_json::from_str(&grouped_block.serialize()).unwrap();
+        assert_eq!(
+            block["minecraft:block"]["description"]["menu_category"],
+            serde_json::json!({ "category": "construction", "group": "violin:gems" })
+        );
+
+        let groups = vec![
+            CreativeGroup::new(
+                Identifier::new("violin", "gems"),
+                MenuCategoryName::Items,
+                Identifier::new("violin", "ruby"),
+            )
+            .using_items(vec![
+                Identifier::new("minecraft", "emerald"),
+                Identifier::new("violin", "ruby"),
+            ]),
+            CreativeGroup::new(
+                Identifier::new("violin", "tools"),
+                MenuCategoryName::Equipment,
+                Identifier::new("minecraft", "diamond_pickaxe"),
+            ),
+        ];
+        let catalog: serde_json::Value = serde_json::from_str(&serialize_crafting_item_catalog(
+            &groups,
+            &[grouped_item, hidden_item],
+            &[grouped_block],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            catalog,
+            serde_json::json!({
+                "format_version": "1.21.60",
+                "minecraft:crafting_items_catalog": {
+                    "categories": [
+                        {
+                            "category_name": "items",
+                            "groups": [{
+                                "group_identifier": {
+                                    "icon": "violin:ruby",
+                                    "name": "violin:gems"
+                                },
+                                "items": ["minecraft:emerald", "violin:ruby", "violin:ruby_block"]
+                            }]
+                        },
+                        {
+                            "category_name": "equipment",
+                            "groups": [{
+                                "group_identifier": {
+                                    "icon": "minecraft:diamond_pickaxe",
+                                    "name": "violin:tools"
+                                },
+                                "items": []
+                            }]
+                        }
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn biome() {
+        let biome = Biome::new(Identifier::new("violin", "crystal_plains"))
+            .using_climate(BiomeClimate::new(0.8, 0.4).using_snow_accumulation([0.0, 0.125]))
+            .using_surface_parameters(BiomeSurfaceParameters::new(
+                Identifier::new("minecraft", "grass_block"),
+                Identifier::new("minecraft", "dirt"),
+                Identifier::new("minecraft", "stone"),
+            ))
+            .using_overworld_generation_rules(
+                BiomeOverworldGenerationRules::new()
+                    .using_generate_for_climates(vec![(BiomeClimateCategory::Medium, 2)]),
+            )
+            .using_tags(vec!["overworld".to_string(), "plains".to_string()])
+            .using_client(
+                ClientBiome::new()
+                    .using_water_surface_color(RGBColor::new(0, 100, 200))
+                    .using_fog_identifier(Identifier::new("violin", "crystal_fog")),
+            );
+        let plain = Biome::new(Identifier::new("violin", "plain"));
+
+        let value: serde_json::Value = serde_json::from_str(&biome.serialize()).unwrap();
+        assert_eq!(value["format_version"], "1.21.50");
+        let components = &value["minecraft:biome"]["components"];
+        assert_eq!(
+            value["minecraft:biome"]["description"]["identifier"],
+            "violin:crystal_plains"
+        );
+        assert_eq!(
+            components["minecraft:climate"],
+            serde_json::json!({
+                "temperature": 0.8,
+                "downfall": 0.4,
+                "snow_accumulation": [0.0, 0.125]
+            })
+        );
+        assert_eq!(
+            components["minecraft:surface_parameters"],
+            serde_json::json!({
+                "top_material": "minecraft:grass_block",
+                "mid_material": "minecraft:dirt",
+                "foundation_material": "minecraft:stone",
+                "sea_floor_material": "minecraft:gravel",
+                "sea_material": "minecraft:water",
+                "sea_floor_depth": 7
+            })
+        );
+        assert_eq!(
+            components["minecraft:overworld_generation_rules"],
+            serde_json::json!({ "generate_for_climates": [["medium", 2]] })
+        );
+        assert_eq!(
+            components["minecraft:tags"],
+            serde_json::json!({ "tags": ["overworld", "plains"] })
+        );
+        let plain_value: serde_json::Value = serde_json::from_str(&plain.serialize()).unwrap();
+        assert_eq!(plain_value["minecraft:biome"]["components"], serde_json::json!({}));
+
+        let client: serde_json::Value =
+            serde_json::from_str(&serialize_client_biomes(&vec![biome.clone(), plain.clone()]))
+                .unwrap();
+        assert_eq!(
+            client,
+            serde_json::json!({
+                "biomes": {
+                    "crystal_plains": {
+                        "fog_identifier": "violin:crystal_fog",
+                        "water_surface_color": "#0064C8"
+                    }
+                }
+            })
+        );
+
+        let mut pack = Pack::new(
+            "Biomes",
+            "biome_test",
+            "NaKeR",
+            SemVer::new(1, 0, 0),
+            "",
+            "./violin_output/dev/development_behavior_packs",
+            "./violin_output/dev/development_resource_packs",
+            Image::default(),
+            None,
+        );
+        pack.register_biome(biome);
+        pack.register_biome(plain);
+        pack.generate();
+
+        let out = std::path::PathBuf::from("./violin_output/packs/biome_test");
+        let generated: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(out.join("BP/biomes/crystal_plains.biome.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(generated, value);
+        assert!(out.join("BP/biomes/plain.biome.json").exists());
+        let generated: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(out.join("RP/biomes_client.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(generated, client);
+    }
+
+    #[test]
+    fn camera_preset() {
+        let preset = CameraPreset::free(Identifier::new("violin", "overview"))
+            .using_position(Vec3 { x: 0.0, y: 120.0, z: 0.0 })
+            .using_rotation([90.0, 0.0])
+            .using_listener(CameraListener::Player)
+            .using_player_effects(false);
+
+        let value: serde_json::Value = serde_json::from_str(&preset.serialize()).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "format_version": "1.19.50",
+                "minecraft:camera_preset": {
+                    "identifier": "violin:overview",
+                    "inherit_from": "minecraft:free",
+                    "pos_x": 0.0,
+                    "pos_y": 120.0,
+                    "pos_z": 0.0,
+                    "rot_x": 90.0,
+                    "rot_y": 0.0,
+                    "listener": "player",
+                    "player_effects": false
+                }
+            })
+        );
+
+        let inherited = CameraPreset::new(
+            Identifier::new("violin", "follow"),
+            Identifier::new("minecraft", "third_person"),
+        );
+        let value: serde_json::Value = serde_json::from_str(&inherited.serialize()).unwrap();
+        assert_eq!(
+            value["minecraft:camera_preset"],
+            serde_json::json!({
+                "identifier": "violin:follow",
+                "inherit_from": "minecraft:third_person"
+            })
+        );
+    }
+
+    #[test]
+    fn fog_setting() {
+        let fog = FogSetting::new(Identifier::new("violin", "crystal_fog"))
+            .using_distance(
+                FogMedium::Air,
+                DistanceFog::new(
+                    0.9,
+                    1.0,
+                    RGBColor::new(171, 210, 255),
+                    FogRenderDistanceType::Render,
+                ),
+            )
+            .using_distance(
+                FogMedium::Water,
+                DistanceFog::new(0.0, 60.0, RGBColor::new(0, 0, 255), FogRenderDistanceType::Fixed),
+            )
+            .using_distance(
+                FogMedium::Water,
+                DistanceFog::new(0.0, 30.0, RGBColor::new(0, 10, 80), FogRenderDistanceType::Fixed),
+            )
+            .using_density(FogMedium::Air, VolumetricDensity::uniform(0.05))
+            .using_media_coefficient(
+                FogMedium::LavaResistance,
+                MediaCoefficient::new([0.1, 0.1, 0.1], [0.2, 0.3, 0.4]),
+            );
+
+        let value: serde_json::Value = serde_json::from_str(&fog.serialize()).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "format_version": "1.16.100",
+                "minecraft:fog_settings": {
+                    "description": { "identifier": "violin:crystal_fog" },
+                    "distance": {
+                        "air": {
+                            "fog_start": 0.9,
+                            "fog_end": 1.0,
+                            "fog_color": "#ABD2FF",
+                            "render_distance_type": "render"
+                        },
+                        "water": {
+                            "fog_start": 0.0,
+                            "fog_end": 30.0,
+                            "fog_color": "#000A50",
+                            "render_distance_type": "fixed"
+                        }
+                    },
+                    "volumetric": {
+                        "density": {
+                            "air": {
+                                "max_density": 0.05,
+                                "max_density_height": 0.0,
+                                "zero_density_height": 0.0,
+                                "uniform": true
+                            }
+                        },
+                        "media_coefficients": {
+                            "lava_resistance": {
+                                "scattering": [0.1, 0.1, 0.1],
+                                "absorption": [0.2, 0.3, 0.4]
+                            }
+                        }
+                    }
+                }
+            })
+        );
+
+        let empty: serde_json::Value = serde_json::from_str(
+            &FogSetting::new(Identifier::new("violin", "empty")).serialize(),
+        )
+        .unwrap();
+        assert_eq!(
+            empty["minecraft:fog_settings"],
+            serde_json::json!({ "description": { "identifier": "violin:empty" } })
+        );
+    }
+
+    #[test]
+    fn generated_build_report() {
+        let small = Block::new(Identifier::new("violin", "small")).using_states(vec![
+            RangedBlockState::new(Identifier::new("violin", "power"), 0, 3).build(),
+        ]);
+        let large = Block::new(Identifier::new("violin", "large")).using_states(vec![
+            RangedBlockState::new(Identifier::new("violin", "power"), 0, 99).build(),
+        ]);
+
+        let mut pack = Pack::new(
+            "Report",
+            "report_test",
+            "NaKeR",
+            SemVer::new(1, 0, 0),
+            "",
+            "./violin_output/dev/development_behavior_packs",
+            "./violin_output/dev/development_resource_packs",
+            Image::default(),
+            None,
+        )
+        .using_permutation_limits(PermutationLimits {
+            max_block_state_combinations: 50,
+            ..Default::default()
+        });
+        pack.register_block(small);
+        pack.register_block(large);
+        pack.generate();
+
+        let out = std::path::PathBuf::from("./violin_output/packs/report_test");
+        assert!(!out.join("BP/blocks/violin_large.block.json").exists());
+        let report: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(out.join("build_report.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(report["blocks"].as_array().unwrap().len(), 1);
+        assert_eq!(report["blocks"][0]["block"], "violin:small");
+        assert_eq!(report["total_state_combinations"], 4);
+    }
+
+    #[test]
+    fn attachable() {
+        let item = Item::new(Identifier::new("violin", "ruby_helmet"));
+        let slot = ItemWearableSlot::ArmorHead;
+        let helmet = Attachable::for_wearable(&item, &slot, Image::default(), "ruby_1").unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&helmet.serialize()).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "format_version": "1.10.0",
+                "minecraft:attachable": {
+                    "description": {
+                        "identifier": "violin:ruby_helmet",
+                        "materials": {
+                            "default": "armor",
+                            "enchanted": "armor_enchanted"
+                        },
+                        "textures": {
+                            "default": "textures/models/armor/ruby_1",
+                            "enchanted": "textures/misc/enchanted_actor_glint"
+                        },
+                        "geometry": {
+                            "default": "geometry.humanoid.armor.helmet"
+                        },
+                        "render_controllers": ["controller.render.armor"],
+                        "scripts": {
+                            "parent_setup": "variable.helmet_layer_visible = 0.0;"
+                        }
+                    }
+                }
+            })
+        );
+        let images = helmet.images();
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].0, "textures/models/armor/ruby_1");
+        assert!(
+            Attachable::for_wearable(&item, &ItemWearableSlot::WeaponOffhand, Image::default(), "x")
+                .is_none()
+        );
+
+        let held = Attachable::new(Identifier::new("violin", "wand"))
+            .using_material("default", "entity_alphatest")
+            .using_texture("default", AttachableTexture::path("textures/items/wand"))
+            .using_geometry("default", "geometry.wand")
+            .using_render_controllers(vec!["controller.render.item_default"])
+            .using_scripts(AttachableScripts::new().using_animate(vec!["hold".to_string()]));
+        assert!(held.images().is_empty());
+        let value: serde_json::Value = serde_json::from_str(&held.serialize()).unwrap();
+        let description = &value["minecraft:attachable"]["description"];
+        assert_eq!(
+            description["textures"],
+            serde_json::json!({ "default": "textures/items/wand" })
+        );
+        assert_eq!(description["scripts"], serde_json::json!({ "animate": ["hold"] }));
+    }
+
+    #[test]
+    fn import_pack_scripts() {
+        let root = std::env::temp_dir().join("violin_import_scripts_test");
+        let _ = std::fs::remove_dir_all(&root);
+        let bp = root.join("scripted_BP");
+        let rp = root.join("scripted_RP");
+        std::fs::create_dir_all(bp.join("scripts")).unwrap();
+        std::fs::create_dir_all(&rp).unwrap();
+
+        let header = serde_json::json!({
+            "name": "Scripted", "description": "", "uuid": "header", "version": [1, 0, 0]
+        });
+        std::fs::write(
+            bp.join("manifest.json"),
+            serde_json::json!({
+                "format_version": 2,
+                "header": header,
+                "modules": [
+                    { "type": "data", "uuid": "bp-module", "version": [1, 0, 0] },
+                    { "type": "script", "uuid": "bp-script", "version": [1, 0, 0] }
+                ],
+                "dependencies": [{ "module_name": "@minecraft/server", "version": "1.16.0" }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+        std::fs::write(
+            rp.join("manifest.json"),
+            serde_json::json!({
+                "format_version": 2,
+                "header": header,
+                "modules": [{ "type": "resources", "uuid": "rp-module", "version": [1, 0, 0] }]
+            })
+            .to_string(),
+        )
+        .unwrap();
+        std::fs::write(bp.join("scripts/main.js"), "import \"./legacy.js\"").unwrap();
+        std::fs::write(bp.join("scripts/legacy.js"), "").unwrap();
+
+        let mut pack = Pack::import(&bp, &rp).unwrap();
+        assert!(pack.has_scripts());
+        assert!(pack.assets().is_empty());
+        FoodItem::new(Identifier::new("scripted", "berry"), 2, 0.6)
+            .using_effects(vec![ConsumeEffect::new("speed", 100, 0)])
+            .register(&mut pack);
+        pack.generate();
+
+        let out = std::path::PathBuf::from("./violin_output/packs/scripted");
+        let main = std::fs::read_to_string(out.join("BP/scripts/main.js")).unwrap();
+        assert!(main.starts_with("import \"./legacy.js\""));
+        assert!(main.contains("scripted_berry_effects.js"));
+        assert!(out.join("BP/scripts/legacy.js").exists());
+    }
+
+    fn item_component_json(component: &impl ItemComponent) -> serde_json::Value {
+        serde_json::json!({ component.id(): component.value() })
+    }
+
+    #[test]
+    fn shooter() {
+        let component = ItemShooterComponent::new(
+            vec![
+                ShooterAmmunition::new(Identifier::new("minecraft", "arrow"))
+                    .using_use_offhand(true),
+            ],
+            true,
+            1.0,
+            true,
+        );
+
+        assert_eq!(
+            item_component_json(&component),
+            serde_json::json!({
+                "minecraft:shooter": {
+                    "ammunition": [{
+                        "item": "minecraft:arrow",
+                        "use_offhand": true,
+                        "search_inventory": true,
+                        "use_in_creative": false
+                    }],
+                    "charge_on_draw": true,
+                    "max_draw_duration": 1.0,
+                    "scale_power_by_draw_duration": true
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn compostable() {
+        assert_eq!(
+            item_component_json(&ItemCompostableComponent::new(65)),
+            serde_json::json!({ "minecraft:compostable": { "composting_chance": 65 } })
+        );
+    }
+
+    #[test]
+    fn swing_duration() {
+        assert_eq!(
+            item_component_json(&ItemSwingDurationComponent::new(0.5)),
+            serde_json::json!({ "minecraft:swing_duration": { "value": 0.5 } })
+        );
+    }
+
+    #[test]
+    fn piercing_weapon() {
+        let component = ItemPiercingWeaponComponent::new(RangeDescriptor::new(0.0, 4.5))
+            .using_hitbox_margin(Some(0.25));
+
+        assert_eq!(
+            item_component_json(&component),
+            serde_json::json!({
+                "minecraft:piercing_weapon": {
+                    "reach": { "min": 0.0, "max": 4.5 },
+                    "hitbox_margin": 0.25
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn kinetic_weapon() {
+        let component =
+            ItemKineticWeaponComponent::new(10, RangeDescriptor::new(2.0, 4.5), 1.5, 0.0)
+                .using_damage_conditions(Some(KineticWeaponConditions::new(300, 0.5, 0.2)));
+
+        assert_eq!(
+            item_component_json(&component),
+            serde_json::json!({
+                "minecraft:kinetic_weapon": {
+                    "delay": 10,
+                    "reach": { "min": 2.0, "max": 4.5 },
+                    "damage_multiplier": 1.5,
+                    "damage_modifier": 0.0,
+                    "damage_conditions": {
+                        "max_duration": 300,
+                        "min_speed": 0.5,
+                        "min_relative_speed": 0.2
+                    }
+                }
+            })
+        );
+    }
+
+    fn block_component_json(component: &impl BlockComponent) -> serde_json::Value {
+        serde_json::json!({ component.id(): component.value() })
+    }
+
+    #[test]
+    fn crafting_table() {
+        let component = BlockCraftingTableComponent::new(
+            "Fletching Table",
+            vec!["crafting_table".to_string(), "violin:fletching".to_string()],
+        );
+
+        assert_eq!(
+            block_component_json(&component),
+            serde_json::json!({
+                "minecraft:crafting_table": {
+                    "table_name": "Fletching Table",
+                    "crafting_tags": ["crafting_table", "violin:fletching"]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn item_visual() {
+        let component = BlockItemVisualComponent::new(
+            BlockGeometryComponent::new("geometry.violin.lamp", std::collections::HashMap::new()),
+            std::collections::HashMap::from([(
+                BlockFace::Up,
+                MaterialInstance::new("violin:lamp", BlockRenderMethod::AlphaTest),
+            )]),
+        );
+
+        assert_eq!(
+            block_component_json(&component),
+            serde_json::json!({
+                "minecraft:item_visual": {
+                    "geometry": {
+                        "identifier": "geometry.violin.lamp",
+                        "bone_visibility": {}
+                    },
+                    "material_instances": {
+                        "up": {
+                            "texture": "violin:lamp",
+                            "render_method": "alpha_test",
+                            "face_dimming": true,
+                            "ambient_occlusion": true
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn destruction_particles() {
+        let component = BlockDestructionParticlesComponent::new("violin:leaves", None, None)
+            .using_tint_method(Some(ParticleTintMethod::BirchFoliage));
+
+        assert_eq!(
+            block_component_json(&component),
+            serde_json::json!({
+                "minecraft:destruction_particles": {
+                    "texture": "violin:leaves",
+                    "tint_method": "birch_foliage"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn replaceable() {
+        assert_eq!(
+            block_component_json(&BlockReplaceableComponent::new()),
+            serde_json::json!({ "minecraft:replaceable": {} })
+        );
+    }
+
+    #[test]
+    fn liquid_detection() {
+        let component = BlockLiquidDetectionComponent::new(vec![LiquidDetectionRule::new(
+            true,
+            LiquidReaction::Blocking,
+        )
+        .using_stops_liquid_flowing_from_direction(vec![BlockFace::Down])]);
+
+        assert_eq!(
+            block_component_json(&component),
+            serde_json::json!({
+                "minecraft:liquid_detection": {
+                    "detection_rules": [{
+                        "liquid_type": "water",
+                        "can_contain_liquid": true,
+                        "on_liquid_touches": "blocking",
+                        "stops_liquid_flowing_from_direction": ["down"],
+                        "use_liquid_clipping": false
+                    }]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn redstone_producer() {
+        let component = BlockRedstoneProducerComponent::new(15, None, None, None)
+            .using_strongly_powered_face(Some(BlockFace::Up))
+            .using_transform_relative(Some(true));
+
+        assert_eq!(
+            block_component_json(&component),
+            serde_json::json!({
+                "minecraft:redstone_producer": {
+                    "power": 15,
+                    "strongly_powered_face": "up",
+                    "transform_relative": true
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn movable() {
+        let component = BlockMovableComponent::new(BlockMovementType::Push, None)
+            .using_sticky(Some(BlockStickyType::Same));
+
+        assert_eq!(
+            block_component_json(&component),
+            serde_json::json!({
+                "minecraft:movable": { "movement_type": "push", "sticky": "same" }
+            })
+        );
+    }
+
+    #[test]
+    fn precipitation_interactions() {
+        assert_eq!(
+            block_component_json(&BlockPrecipitationInteractionsComponent::new(
+                PrecipitationBehavior::ObstructRain
+            )),
+            serde_json::json!({
+                "minecraft:precipitation_interactions": {
+                    "precipitation_behavior": "obstruct_rain"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn random_offset() {
+        let axis = RandomOffsetAxis::new(16, -0.25, 0.25);
+        let component = BlockRandomOffsetComponent::new(Some(axis.clone()), None, Some(axis));
+
+        assert_eq!(
+            block_component_json(&component),
+            serde_json::json!({
+                "minecraft:random_offset": {
+                    "x": { "steps": 16, "range": { "min": -0.25, "max": 0.25 } },
+                    "z": { "steps": 16, "range": { "min": -0.25, "max": 0.25 } }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn rgb_color_hex() {
+        assert_eq!(RGBColor::new(255, 128, 0).render_as_hex(), "#FF8000");
+        assert_eq!(RGBColor::new(1, 10, 171).render_as_hex(), "#010AAB");
+    }
 }
@@ -42,15 +42,15 @@ impl Localization {
         let mut serialized = String::new();
 
         for (id, value) in self.item_names.clone() {
-            serialized.push_str(&format!("item.{}.name={}", id, value))
+            serialized.push_str(&format!("item.{}.name={}\n", id, value))
         }
 
         for (id, value) in self.block_names.clone() {
-            serialized.push_str(&format!("tile.{}.name={}", id, value))
+            serialized.push_str(&format!("tile.{}.name={}\n", id, value))
         }
 
         for (id, value) in self.customs.clone() {
-            serialized.push_str(&format!("{}={}", id, value))
+            serialized.push_str(&format!("{}={}\n", id, value))
         }
 
         serialized
@@ -21,3 +21,14 @@ pub fn error(str: String, additional: String) {
     );
     println!("{}", output.as_str());
 }
+
+pub fn warn(str: String, additional: String) {
+    let output = format!(
+        "{} {} {} {}",
+        "[ Violin ]".bright_magenta(),
+        "[ WARN ]".bright_yellow(),
+        additional.bright_yellow(),
+        str
+    );
+    println!("{}", output.as_str());
+}
@@ -1,32 +1,49 @@
-use crate::block::block_registry::BlockTexture;
+use crate::asset::{AssetSource, PackAsset};
+use crate::biome::{serialize_client_biomes, Biome};
+use crate::block::block_registry::{BlockTexture, FlipbookTexture};
+use crate::block::culling::BlockCullingRules;
 use crate::block::block_registry::{
-    serialize_block_atlas, serialize_terrain_atlas, BlockAtlasEntry, BlockAtlasTemplate,
-    BlockRegistry, TerrainAtlasEntry, TerrainAtlasTemplate,
+    serialize_block_atlas, serialize_flipbook_textures, serialize_terrain_atlas,
+    BlockAtlasEntry, BlockAtlasTemplate, BlockRegistry, TerrainAtlasEntry, TerrainAtlasTemplate,
 };
+use crate::block::utils::BlockDescriptor;
 use crate::block::Block;
+use crate::camera::CameraPreset;
+use crate::feature::rule::FeatureRule;
+use crate::feature::Feature;
+use crate::fog::FogSetting;
 use crate::image::Image;
+use crate::import::{
+    find_texture, pack_files, read_block_atlas_entry, read_json, read_localization, read_manifest,
+    read_texture_data,
+};
+use crate::item::attachable::Attachable;
+use crate::item::catalog::{serialize_crafting_item_catalog, CreativeGroup};
 use crate::item::item_registry::{serialize_item_atlas, ItemTexture};
 use crate::item::ItemAtlasTemplate;
 use crate::item::{item_registry::ItemRegistry, Item};
 use crate::localization::Localization;
-use crate::logger::info;
-use crate::recipe::Recipe;
+use crate::logger::{error, info, warn};
+use crate::recipe::{recipe_from_json, validate_recipe_tags, Recipe};
+use crate::report::{BlockReport, BuildReport, PermutationLimits};
 use crate::template::{BpManifestTemplate, RpManifestTemplate};
-use crate::vio::SemVer;
+use crate::tag::{Tag, TagQuery, TagRegistry};
+use crate::texture_set::TextureSet;
+use crate::vio::{Identifier, ParseError, SemVer};
 use askama::Template;
 use fs_extra::dir;
 use serde_json::Value;
 use std::{fs, io};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::string::ToString;
 use std::sync::{Arc, RwLock};
 use regex::RegexBuilder;
 use uuid::Uuid;
-use crate::script::ScriptData;
+use crate::script::{ScriptAddition, ScriptData};
 
 const RESULT_FOLDER: &str = "violin_output";
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 #[derive(PartialEq, Eq)]
 pub enum PackPart {
     BP,
@@ -48,6 +65,28 @@ pub struct Pack {
     recipes: Vec<Arc<dyn Recipe>>,
     block_registry: BlockRegistry,
     localizations: Vec<Localization>,
+    features: Vec<Arc<dyn Feature>>,
+    feature_rules: Vec<FeatureRule>,
+    biomes: Vec<Biome>,
+    fogs: Vec<FogSetting>,
+    camera_presets: Vec<CameraPreset>,
+    creative_groups: Vec<CreativeGroup>,
+    assets: Vec<PackAsset>,
+    manifest_uuids: Option<ManifestUuids>,
+    permutation_limits: PermutationLimits,
+    block_texture_sets: Vec<TextureSet>,
+    item_texture_sets: Vec<TextureSet>,
+    tag_registry: TagRegistry,
+}
+
+/// UUIDs of the manifests of an imported pack, reused when generating it.
+#[derive(Clone)]
+struct ManifestUuids {
+    bp_header: String,
+    bp_data: String,
+    bp_script: Option<String>,
+    rp_header: String,
+    rp_resources: String,
 }
 
 impl Pack {
@@ -85,10 +124,318 @@ impl Pack {
             recipes: Vec::new(),
             block_registry: BlockRegistry::new(),
             localizations: Vec::new(),
+            features: Vec::new(),
+            feature_rules: Vec::new(),
+            biomes: Vec::new(),
+            fogs: Vec::new(),
+            camera_presets: Vec::new(),
+            creative_groups: Vec::new(),
+            assets: Vec::new(),
+            manifest_uuids: None,
+            permutation_limits: PermutationLimits::default(),
+            block_texture_sets: Vec::new(),
+            item_texture_sets: Vec::new(),
+            tag_registry: TagRegistry::new(),
         };
         pack
     }
 
+    /// Reads an existing behavior and resource pack. Manifests, items, blocks,
+    /// recipes, texture atlases, textures and `.lang` files are imported into
+    /// the matching registries, every other file is carried through as is.
+    ///
+    /// The pack id is the BP folder name without a `_BP` suffix. Dev folders
+    /// default to `violin_output/dev`, see `using_dev_folders`.
+    pub fn import(
+        bp_dir: impl Into<PathBuf>,
+        rp_dir: impl Into<PathBuf>,
+    ) -> Result<Self, ParseError> {
+        let bp_dir: PathBuf = bp_dir.into();
+        let rp_dir: PathBuf = rp_dir.into();
+
+        let bp_manifest = read_manifest(&bp_dir.join("manifest.json"))?;
+        let rp_manifest = read_manifest(&rp_dir.join("manifest.json"))?;
+
+        let folder_name = bp_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let id = folder_name
+            .strip_suffix("_BP")
+            .unwrap_or(&folder_name)
+            .to_string();
+
+        let icon = Image::open(bp_dir.join("pack_icon.png"))
+            .or_else(|| Image::open(rp_dir.join("pack_icon.png")))
+            .unwrap_or_default();
+
+        let scripts = match bp_manifest.script_module_uuid {
+            Some(_) => ScriptData::new(
+                bp_manifest
+                    .server_version
+                    .clone()
+                    .unwrap_or(SemVer::new(1, 0, 0)),
+                bp_manifest
+                    .server_ui_version
+                    .clone()
+                    .unwrap_or(SemVer::new(1, 0, 0)),
+                bp_dir.join("scripts").to_string_lossy().to_string(),
+            ),
+            None => None,
+        };
+
+        let mut pack = Self::new(
+            bp_manifest.name.clone(),
+            id,
+            bp_manifest.author.clone(),
+            bp_manifest.version.clone(),
+            bp_manifest.description.clone(),
+            format!("./{RESULT_FOLDER}/dev/development_behavior_packs"),
+            format!("./{RESULT_FOLDER}/dev/development_resource_packs"),
+            icon,
+            scripts,
+        );
+        pack.manifest_uuids = Some(ManifestUuids {
+            bp_header: bp_manifest.header_uuid,
+            bp_data: bp_manifest.module_uuid,
+            bp_script: bp_manifest.script_module_uuid,
+            rp_header: rp_manifest.header_uuid,
+            rp_resources: rp_manifest.module_uuid,
+        });
+
+        pack.import_bp(&bp_dir);
+        pack.import_rp(&rp_dir);
+
+        Ok(pack)
+    }
+
+    pub fn using_dev_folders(
+        self,
+        dev_bp_folder: impl Into<String>,
+        dev_rp_folder: impl Into<String>,
+    ) -> Self {
+        Self {
+            dev_bp_folder: dev_bp_folder.into(),
+            dev_rp_folder: dev_rp_folder.into(),
+            ..self
+        }
+    }
+
+    pub fn using_permutation_limits(self, permutation_limits: PermutationLimits) -> Self {
+        Self {
+            permutation_limits,
+            ..self
+        }
+    }
+
+    /// State combinations and unmatchable permutations of the registered
+    /// blocks.
+    pub fn build_report(&self) -> BuildReport {
+        BuildReport::new(&self.block_registry.blocks)
+    }
+
+    fn import_bp(&mut self, bp_dir: &Path) {
+        for file in pack_files(bp_dir) {
+            let path = bp_dir.join(&file);
+            let is_json = file.ends_with(".json");
+
+            // Scripts are paired from the folder and extended by script
+            // additions, so they aren't copied again as assets.
+            let imported = if file == "manifest.json"
+                || file == "pack_icon.png"
+                || (file.starts_with("scripts/") && self.scripts.is_some())
+            {
+                true
+            } else if file.starts_with("items/") && is_json {
+                match fs::read_to_string(&path).map(|src| Item::from_json(&src)) {
+                    Ok(Ok(item)) => {
+                        self.register_item(item);
+                        true
+                    }
+                    Ok(Err(err)) => self.import_failed(&file, err),
+                    Err(err) => self.import_failed(&file, err.into()),
+                }
+            } else if file.starts_with("blocks/") && is_json {
+                match fs::read_to_string(&path).map(|src| Block::from_json(&src)) {
+                    Ok(Ok(block)) => {
+                        self.register_block(block);
+                        true
+                    }
+                    Ok(Err(err)) => self.import_failed(&file, err),
+                    Err(err) => self.import_failed(&file, err.into()),
+                }
+            } else if file.starts_with("recipes/") && is_json {
+                match fs::read_to_string(&path).map(|src| recipe_from_json(&src)) {
+                    Ok(Ok(recipe)) => {
+                        self.register_recipe(recipe);
+                        true
+                    }
+                    Ok(Err(err)) => self.import_failed(&file, err),
+                    Err(err) => self.import_failed(&file, err.into()),
+                }
+            } else {
+                false
+            };
+
+            if !imported {
+                self.assets
+                    .push(PackAsset::new(PackPart::BP, file, AssetSource::File(path)));
+            }
+        }
+    }
+
+    fn import_rp(&mut self, rp_dir: &Path) {
+        let mut imported_files = vec!["manifest.json".to_string(), "pack_icon.png".to_string()];
+
+        if let Ok(atlas) = read_json(&rp_dir.join("textures/item_texture.json")) {
+            let (entries, skipped) = read_texture_data(&atlas);
+            let mut complete = skipped.is_empty();
+
+            for (key, texture_path) in entries {
+                let texture = texture_path
+                    .strip_prefix("textures/items/")
+                    .and_then(|file_name| {
+                        find_texture(rp_dir, &texture_path)
+                            .map(|(file, src)| (file_name, file, src))
+                    });
+
+                match texture {
+                    Some((file_name, file, src)) => {
+                        self.register_item_texture(ItemTexture::new(key, file_name, src));
+                        imported_files.push(file);
+                    }
+                    None => {
+                        warn(
+                            format!(
+                                "Item texture \"{}\" isn't in textures/items and can't be imported",
+                                key
+                            ),
+                            "[ IMPORT ]".to_string(),
+                        );
+                        complete = false;
+                    }
+                }
+            }
+            for key in skipped {
+                warn(
+                    format!(
+                        "Item texture \"{}\" has no single texture path and can't be imported",
+                        key
+                    ),
+                    "[ IMPORT ]".to_string(),
+                );
+            }
+
+            self.import_atlas("textures/item_texture.json", complete, &mut imported_files);
+        }
+
+        if let Ok(atlas) = read_json(&rp_dir.join("textures/terrain_texture.json")) {
+            let (entries, skipped) = read_texture_data(&atlas);
+            let complete = skipped.is_empty();
+
+            for (key, texture_path) in entries {
+                if let Some(file_name) = texture_path.strip_prefix("textures/blocks/") {
+                    if let Some((file, src)) = find_texture(rp_dir, &texture_path) {
+                        self.block_registry.add_texture(BlockTexture::new(
+                            src,
+                            Identifier::default(),
+                            file_name,
+                        ));
+                        imported_files.push(file);
+                    }
+                }
+                self.block_registry
+                    .add_terrain_atlas_entry(TerrainAtlasEntry {
+                        id: key,
+                        texture_path,
+                    });
+            }
+            for key in skipped {
+                warn(
+                    format!(
+                        "Terrain texture \"{}\" has no single texture path and can't be imported",
+                        key
+                    ),
+                    "[ IMPORT ]".to_string(),
+                );
+            }
+
+            self.import_atlas("textures/terrain_texture.json", complete, &mut imported_files);
+        }
+
+        if let Ok(atlas) = read_json(&rp_dir.join("blocks.json")) {
+            let mut complete = true;
+
+            for (key, entry) in atlas.as_object().into_iter().flatten() {
+                if key == "format_version" {
+                    continue;
+                }
+
+                match read_block_atlas_entry(key, entry) {
+                    Some(entry) => self.register_block_atlas_entry(entry),
+                    None => {
+                        warn(
+                            format!(
+                                "Block atlas entry \"{}\" isn't supported and can't be imported",
+                                key
+                            ),
+                            "[ IMPORT ]".to_string(),
+                        );
+                        complete = false;
+                    }
+                }
+            }
+
+            self.import_atlas("blocks.json", complete, &mut imported_files);
+        }
+
+        for file in pack_files(rp_dir) {
+            let path = rp_dir.join(&file);
+
+            if let Some(language) = file
+                .strip_prefix("texts/")
+                .and_then(|name| name.strip_suffix(".lang"))
+            {
+                if let Ok(src) = fs::read_to_string(&path) {
+                    self.add_localization(read_localization(language, &src));
+                    continue;
+                }
+            }
+
+            if !imported_files.contains(&file) {
+                self.assets
+                    .push(PackAsset::new(PackPart::RP, file, AssetSource::File(path)));
+            }
+        }
+    }
+
+    /// Marks an atlas as imported if all of its entries were. Otherwise the
+    /// file is carried through as is, replacing the generated atlas, so no
+    /// entry is lost.
+    fn import_atlas(&self, file: &str, complete: bool, imported_files: &mut Vec<String>) {
+        if complete {
+            imported_files.push(file.to_string());
+        } else {
+            warn(
+                format!(
+                    "Not every entry of \"{}\" could be imported, copying it as is",
+                    file
+                ),
+                "[ IMPORT ]".to_string(),
+            );
+        }
+    }
+
+    /// Logs a file that couldn't be imported. It's carried through as is.
+    fn import_failed(&self, file: &str, err: ParseError) -> bool {
+        error(
+            format!("Cannot import \"{}\", copying it as is: {}", file, err),
+            "[ IMPORT ]".to_string(),
+        );
+
+        false
+    }
+
     pub fn generate(&mut self) -> () {
         info(
             format!("Creating Pack \"{}\"(\"{}\")", &self.name, &self.id),
@@ -147,6 +494,16 @@ impl Pack {
             }
         }
 
+        if let Some(uuids) = &self.manifest_uuids {
+            bp_uuid_1 = uuids.bp_header.clone();
+            bp_uuid_2 = uuids.bp_data.clone();
+            if let Some(script) = &uuids.bp_script {
+                bp_uuid_3 = script.clone();
+            }
+            rp_uuid_1 = uuids.rp_header.clone();
+            rp_uuid_2 = uuids.rp_resources.clone();
+        }
+
         if let Ok(
             _
         ) = fs::read_dir(format!("./{RESULT_FOLDER}/packs/{}/BP", &self.id)) {
@@ -210,6 +567,7 @@ impl Pack {
             uuid_1: rp_uuid_1.as_str(),
             uuid_2: rp_uuid_2.as_str(),
             version: &self.version.render_commas(),
+            pbr: &(!self.block_texture_sets.is_empty() || !self.item_texture_sets.is_empty()),
         }
         .render()
         .unwrap();
@@ -246,10 +604,62 @@ impl Pack {
             self.generate_script_additions();
         }
 
+        self.apply_tags();
         self.generate_items();
         self.generate_blocks();
+        self.generate_texture_sets();
+        self.generate_item_catalog();
         self.generate_recipes();
+        self.generate_features();
+        self.generate_biomes();
+        self.generate_fogs();
+        self.generate_camera_presets();
         self.generate_localizations();
+        self.generate_assets();
+    }
+
+    /// Tags the block when the pack is generated, as `tag:` components.
+    pub fn tag_block(&mut self, block: Identifier, tags: Vec<Tag>) {
+        info(
+            format!("Tagging Block \"{}\"", block.render()),
+            "[ TAG ]".to_string(),
+        );
+        self.tag_registry.tag_block(block, tags);
+    }
+
+    /// Tags the item when the pack is generated, in its `minecraft:tags`.
+    pub fn tag_item(&mut self, item: Identifier, tags: Vec<Tag>) {
+        info(
+            format!("Tagging Item \"{}\"", item.render()),
+            "[ TAG ]".to_string(),
+        );
+        self.tag_registry.tag_item(item, tags);
+    }
+
+    /// A block descriptor for the blocks matching the query, e.g. for a
+    /// digger. A warning is logged on `generate` for every tag of the query
+    /// that's neither vanilla nor on any block of the pack.
+    pub fn block_descriptor(&mut self, query: TagQuery) -> BlockDescriptor {
+        self.tag_registry.block_descriptor(query)
+    }
+
+    fn apply_tags(&mut self) {
+        self.block_registry.blocks = self
+            .block_registry
+            .blocks
+            .iter()
+            .map(|block| self.tag_registry.apply_to_block(block))
+            .collect();
+        self.item_registry.items = self
+            .item_registry
+            .items
+            .iter()
+            .map(|item| self.tag_registry.apply_to_item(item))
+            .collect();
+
+        for issue in self.tag_registry.validate(&self.block_registry.blocks) {
+            warn(issue.to_string(), "[ TAG ]".to_string());
+        }
     }
 
     pub fn register_recipe<'b>(&mut self, recipe: Arc<dyn Recipe>) {
@@ -260,6 +670,46 @@ impl Pack {
         );
     }
 
+    pub fn register_feature(&mut self, feature: Arc<dyn Feature>) {
+        info(
+            format!("Registering Feature \"{}\"", feature.id().render()),
+            "[ FEATURE ]".to_string(),
+        );
+        self.features.push(feature);
+    }
+
+    pub fn register_feature_rule(&mut self, rule: FeatureRule) {
+        info(
+            format!("Registering Feature Rule \"{}\"", rule.id().render()),
+            "[ FEATURE ]".to_string(),
+        );
+        self.feature_rules.push(rule);
+    }
+
+    pub fn register_biome(&mut self, biome: Biome) {
+        info(
+            format!("Registering Biome \"{}\"", biome.id().render()),
+            "[ BIOME ]".to_string(),
+        );
+        self.biomes.push(biome);
+    }
+
+    pub fn register_fog(&mut self, fog: FogSetting) {
+        info(
+            format!("Registering Fog \"{}\"", fog.id().render()),
+            "[ FOG ]".to_string(),
+        );
+        self.fogs.push(fog);
+    }
+
+    pub fn register_camera_preset(&mut self, preset: CameraPreset) {
+        info(
+            format!("Registering Camera Preset \"{}\"", preset.id().render()),
+            "[ CAMERA ]".to_string(),
+        );
+        self.camera_presets.push(preset);
+    }
+
     pub fn register_item(&mut self, item: Item) {
         self.item_registry.add_item(item.clone());
         info(
@@ -276,6 +726,85 @@ impl Pack {
         self.item_registry.add_texture(texture);
     }
 
+    /// Registers PBR layers for the item texture of the same name. The RP
+    /// manifest gets the `pbr` capability once any texture set is registered.
+    pub fn register_item_texture_set(&mut self, texture_set: TextureSet) {
+        info(
+            format!("Registering Item Texture Set \"{}\"", texture_set.name),
+            "[ ITEM ][ TEXTURE ]".to_string(),
+        );
+        self.item_texture_sets.push(texture_set);
+    }
+
+    pub fn register_attachable(&mut self, attachable: Attachable) {
+        info(
+            format!("Registering Attachable \"{}\"", attachable.id().render()),
+            "[ ITEM ][ ATTACHABLE ]".to_string(),
+        );
+        self.item_registry.add_attachable(attachable);
+    }
+
+    pub fn register_creative_group(&mut self, group: CreativeGroup) {
+        info(
+            format!("Registering Creative Group \"{}\"", group.id.render()),
+            "[ ITEM ][ CATALOG ]".to_string(),
+        );
+        self.creative_groups.push(group);
+    }
+
+    /// Registers a file Violin doesn't model, written to `path` below the BP
+    /// or RP root on every `generate`. Assets are written after everything
+    /// else, so they replace generated files with the same path.
+    ///
+    /// Registering the same path again replaces the earlier asset, with a
+    /// warning if the contents differ.
+    pub fn register_asset(
+        &mut self,
+        part: PackPart,
+        path: impl Into<String>,
+        source: AssetSource,
+    ) {
+        let asset = PackAsset::new(part, path, source);
+
+        if !asset.has_valid_path() {
+            error(
+                format!(
+                    "Asset path \"{}\" has to be relative to the pack root, skipping it",
+                    asset.path
+                ),
+                "[ ASSET ]".to_string(),
+            );
+            return;
+        }
+
+        if let Some(index) = self
+            .assets
+            .iter()
+            .position(|existing| existing.part == asset.part && existing.path == asset.path)
+        {
+            let existing = self.assets.remove(index);
+            if existing.source != asset.source {
+                warn(
+                    format!(
+                        "Asset \"{}\" was already registered, replacing it",
+                        asset.path
+                    ),
+                    "[ ASSET ]".to_string(),
+                );
+            }
+        }
+
+        info(
+            format!("Registering Asset \"{}\"", asset.path),
+            "[ ASSET ]".to_string(),
+        );
+        self.assets.push(asset);
+    }
+
+    pub fn assets(&self) -> &[PackAsset] {
+        &self.assets
+    }
+
     pub fn register_block_texture(&mut self, texture: BlockTexture) {
         info(
             format!(
@@ -292,6 +821,20 @@ impl Pack {
             });
     }
 
+    /// Registers the strip of the flipbook as a block texture and adds it to
+    /// `flipbook_textures.json`.
+    pub fn register_flipbook_texture(&mut self, flipbook: FlipbookTexture) {
+        info(
+            format!(
+                "Registering Flipbook Texture \"{}\"",
+                flipbook.atlas_tile().render()
+            ),
+            "[ BLOCK ][ TEXTURE ]".to_string(),
+        );
+        self.register_block_texture(flipbook.texture.clone());
+        self.block_registry.flipbooks.push(flipbook);
+    }
+
     pub fn register_block_atlas_entry(&mut self, entry: Arc<dyn BlockAtlasEntry>) {
         self.block_registry.block_atlas.push(entry.clone());
         info(
@@ -322,8 +865,16 @@ impl Pack {
                 .into_iter()
                 .map(|el| if el == ':' { '_' } else { el })
                 .collect();
-            let content = item.serialize();
-            let pretty_content = jsonxf::pretty_print(&content).unwrap();
+            let pretty_content = match item.to_value() {
+                Ok(value) => serde_json::to_string_pretty(&value).unwrap(),
+                Err(err) => {
+                    error(
+                        format!("Item \"{}\": {}", &item.type_id().render(), err),
+                        "[ ITEM ]".to_string(),
+                    );
+                    continue;
+                }
+            };
             let _ = match fs::write(
                 format!(
                     "./{RESULT_FOLDER}/packs/{}/BP/items/{}.item.json",
@@ -341,6 +892,51 @@ impl Pack {
         }
 
         self.generate_item_atlas();
+        self.generate_attachables();
+    }
+
+    fn generate_attachables(&self) {
+        if self.item_registry.attachables.is_empty() {
+            return;
+        }
+
+        let _ = fs::create_dir_all(format!("./{RESULT_FOLDER}/packs/{}/RP/attachables/", &self.id));
+
+        for attachable in self.item_registry.attachables.iter() {
+            info(
+                format!("Generating Attachable \"{}\"", attachable.id().render()),
+                "[ ITEM ][ ATTACHABLE ]".to_string(),
+            );
+            let file_name: String = attachable
+                .id()
+                .render()
+                .chars()
+                .map(|el| if el == ':' { '_' } else { el })
+                .collect();
+            let content = attachable.serialize();
+            let pretty_content = jsonxf::pretty_print(&content).unwrap();
+            let _ = match fs::write(
+                format!(
+                    "./{RESULT_FOLDER}/packs/{}/RP/attachables/{}.json",
+                    &self.id, &file_name
+                ),
+                pretty_content,
+            ) {
+                Ok(_) => "Ok!",
+                Err(_) => "Err!",
+            };
+
+            for (path, src) in attachable.images() {
+                let path = PathBuf::from(format!(
+                    "./{RESULT_FOLDER}/packs/{}/RP/{}.png",
+                    &self.id, path
+                ));
+                if let Some(parent) = path.parent() {
+                    let _ = fs::create_dir_all(parent);
+                }
+                src.build(path);
+            }
+        }
     }
 
     fn generate_item_atlas(&self) {
@@ -357,11 +953,15 @@ impl Pack {
 
         for entry in &self.item_registry.item_atlas {
             let file_name: String = entry.clone().file_name;
-
-            entry.src.build(PathBuf::from(format!(
+            let path = PathBuf::from(format!(
                 "./{RESULT_FOLDER}/packs/{}/RP/textures/items/{}.png",
                 &self.id, &file_name
-            )))
+            ));
+            if let Some(parent) = path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+
+            entry.src.build(path)
         }
         let content = jsonxf::pretty_print(content_raw.as_str()).unwrap();
         let _ = match fs::write(
@@ -376,6 +976,33 @@ impl Pack {
         };
     }
 
+    fn generate_item_catalog(&self) {
+        if self.creative_groups.is_empty() {
+            return;
+        }
+
+        let _ = fs::create_dir_all(format!(
+            "./{RESULT_FOLDER}/packs/{}/BP/item_catalog/",
+            &self.id
+        ));
+        let content = serialize_crafting_item_catalog(
+            &self.creative_groups,
+            &self.item_registry.items,
+            &self.block_registry.blocks,
+        );
+        let pretty_content = jsonxf::pretty_print(&content).unwrap();
+        let _ = match fs::write(
+            format!(
+                "./{RESULT_FOLDER}/packs/{}/BP/item_catalog/crafting_item_catalog.json",
+                &self.id
+            ),
+            pretty_content,
+        ) {
+            Ok(_) => "Ok!",
+            Err(_) => "Err!",
+        };
+    }
+
     pub fn build_to_dev(&self) {
         let _ = fs::remove_dir_all(format!("{}/{}_BP", &self.dev_bp_folder, &self.id));
         let _ = fs::remove_dir_all(format!("{}/{}_RP", &self.dev_rp_folder, &self.id));
@@ -421,6 +1048,10 @@ impl Pack {
 
     fn generate_recipes(&self) {
         let _ = fs::create_dir_all(format!("./{RESULT_FOLDER}/packs/{}/BP/recipes/", &self.id));
+        for issue in validate_recipe_tags(&self.recipes, &self.block_registry.blocks) {
+            warn(issue.to_string(), "[ RECIPE ]".to_string());
+        }
+
         let iterator: Vec<Arc<dyn Recipe>> = self.recipes.clone();
         for recipe in iterator {
             info(
@@ -449,10 +1080,158 @@ impl Pack {
         }
     }
 
+    fn generate_features(&self) {
+        let _ = fs::create_dir_all(format!("./{RESULT_FOLDER}/packs/{}/BP/features/", &self.id));
+        let _ = fs::create_dir_all(format!(
+            "./{RESULT_FOLDER}/packs/{}/BP/feature_rules/",
+            &self.id
+        ));
+
+        for feature in self.features.iter() {
+            info(
+                format!("Generating Feature \"{}\"", feature.id().render()),
+                "[ FEATURE ]".to_string(),
+            );
+            let content = feature.serialize();
+            let pretty_content = jsonxf::pretty_print(&content).unwrap();
+            let _ = match fs::write(
+                format!(
+                    "./{RESULT_FOLDER}/packs/{}/BP/features/{}.json",
+                    &self.id,
+                    feature.id().value
+                ),
+                pretty_content,
+            ) {
+                Ok(_) => "Ok!",
+                Err(_) => "Err!",
+            };
+        }
+
+        for rule in self.feature_rules.iter() {
+            info(
+                format!("Generating Feature Rule \"{}\"", rule.id().render()),
+                "[ FEATURE ]".to_string(),
+            );
+            let content = rule.serialize();
+            let pretty_content = jsonxf::pretty_print(&content).unwrap();
+            let _ = match fs::write(
+                format!(
+                    "./{RESULT_FOLDER}/packs/{}/BP/feature_rules/{}.json",
+                    &self.id,
+                    rule.id().value
+                ),
+                pretty_content,
+            ) {
+                Ok(_) => "Ok!",
+                Err(_) => "Err!",
+            };
+        }
+    }
+
+    fn generate_biomes(&self) {
+        if self.biomes.is_empty() {
+            return;
+        }
+
+        let _ = fs::create_dir_all(format!("./{RESULT_FOLDER}/packs/{}/BP/biomes/", &self.id));
+
+        for biome in self.biomes.iter() {
+            info(
+                format!("Generating Biome \"{}\"", biome.id().render()),
+                "[ BIOME ]".to_string(),
+            );
+            let content = biome.serialize();
+            let pretty_content = jsonxf::pretty_print(&content).unwrap();
+            let _ = match fs::write(
+                format!(
+                    "./{RESULT_FOLDER}/packs/{}/BP/biomes/{}.biome.json",
+                    &self.id,
+                    biome.id().value
+                ),
+                pretty_content,
+            ) {
+                Ok(_) => "Ok!",
+                Err(_) => "Err!",
+            };
+        }
+
+        if self.biomes.iter().any(|biome| biome.client.is_some()) {
+            let content = serialize_client_biomes(&self.biomes);
+            let pretty_content = jsonxf::pretty_print(&content).unwrap();
+            let _ = match fs::write(
+                format!("./{RESULT_FOLDER}/packs/{}/RP/biomes_client.json", &self.id),
+                pretty_content,
+            ) {
+                Ok(_) => "Ok!",
+                Err(_) => "Err!",
+            };
+        }
+    }
+
+    fn generate_fogs(&self) {
+        if self.fogs.is_empty() {
+            return;
+        }
+
+        let _ = fs::create_dir_all(format!("./{RESULT_FOLDER}/packs/{}/RP/fogs/", &self.id));
+
+        for fog in self.fogs.iter() {
+            info(
+                format!("Generating Fog \"{}\"", fog.id().render()),
+                "[ FOG ]".to_string(),
+            );
+            let content = fog.serialize();
+            let pretty_content = jsonxf::pretty_print(&content).unwrap();
+            let _ = match fs::write(
+                format!(
+                    "./{RESULT_FOLDER}/packs/{}/RP/fogs/{}.json",
+                    &self.id,
+                    fog.id().value
+                ),
+                pretty_content,
+            ) {
+                Ok(_) => "Ok!",
+                Err(_) => "Err!",
+            };
+        }
+    }
+
+    fn generate_camera_presets(&self) {
+        if self.camera_presets.is_empty() {
+            return;
+        }
+
+        let _ = fs::create_dir_all(format!(
+            "./{RESULT_FOLDER}/packs/{}/BP/cameras/presets/",
+            &self.id
+        ));
+
+        for preset in self.camera_presets.iter() {
+            info(
+                format!("Generating Camera Preset \"{}\"", preset.id().render()),
+                "[ CAMERA ]".to_string(),
+            );
+            let content = preset.serialize();
+            let pretty_content = jsonxf::pretty_print(&content).unwrap();
+            let _ = match fs::write(
+                format!(
+                    "./{RESULT_FOLDER}/packs/{}/BP/cameras/presets/{}.json",
+                    &self.id,
+                    preset.id().value
+                ),
+                pretty_content,
+            ) {
+                Ok(_) => "Ok!",
+                Err(_) => "Err!",
+            };
+        }
+    }
+
     fn generate_blocks(&mut self) {
         let _ = fs::create_dir_all(format!("./{RESULT_FOLDER}/packs/{}/BP/blocks/", &self.id));
         let _ = fs::create_dir_all(format!("./{RESULT_FOLDER}/packs/{}/RP/textures/", &self.id));
 
+        let mut reports = vec![];
         for block in self.block_registry.blocks.iter() {
             extern crate jsonxf;
             info(
@@ -466,8 +1245,59 @@ impl Pack {
                 .into_iter()
                 .map(|el| if el == ':' { '_' } else { el })
                 .collect();
-            let content = block.serialize();
-            let pretty_content = jsonxf::pretty_print(&content).unwrap();
+            if let Err(errors) = block.validate() {
+                for err in errors {
+                    error(
+                        format!("Block \"{}\": {}", &block.type_id().render(), err),
+                        "[ BLOCK ]".to_string(),
+                    );
+                }
+                continue;
+            }
+            let report = BlockReport::new(block);
+            if report.state_combinations > self.permutation_limits.max_block_state_combinations {
+                error(
+                    format!(
+                        "Block \"{}\" has {} state combinations, more than the limit of {}",
+                        &block.type_id().render(),
+                        report.state_combinations,
+                        self.permutation_limits.max_block_state_combinations
+                    ),
+                    "[ BLOCK ]".to_string(),
+                );
+                continue;
+            }
+            if report.state_combinations > self.permutation_limits.warn_block_state_combinations {
+                warn(
+                    format!(
+                        "Block \"{}\" has {} state combinations",
+                        &block.type_id().render(),
+                        report.state_combinations
+                    ),
+                    "[ BLOCK ]".to_string(),
+                );
+            }
+            for condition in &report.unmatchable_permutations {
+                warn(
+                    format!(
+                        "Block \"{}\": No state combination matches permutation \"{}\"",
+                        &block.type_id().render(),
+                        condition
+                    ),
+                    "[ BLOCK ]".to_string(),
+                );
+            }
+            let pretty_content = match block.to_value() {
+                Ok(value) => serde_json::to_string_pretty(&value).unwrap(),
+                Err(err) => {
+                    error(
+                        format!("Block \"{}\": {}", &block.type_id().render(), err),
+                        "[ BLOCK ]".to_string(),
+                    );
+                    continue;
+                }
+            };
+            reports.push(report);
             let _ = match fs::write(
                 format!(
                     "./{RESULT_FOLDER}/packs/{}/BP/blocks/{}.block.json",
@@ -484,20 +1314,82 @@ impl Pack {
             ));
         }
 
+        let report = BuildReport::from_reports(reports);
+        if report.total_state_combinations > self.permutation_limits.max_pack_state_combinations {
+            error(
+                format!(
+                    "The blocks have {} state combinations in total, more than the limit of {}",
+                    report.total_state_combinations,
+                    self.permutation_limits.max_pack_state_combinations
+                ),
+                "[ BLOCK ]".to_string(),
+            );
+        }
+        if let Err(err) = fs::write(
+            format!("./{RESULT_FOLDER}/packs/{}/build_report.json", &self.id),
+            serde_json::to_string_pretty(&report).unwrap(),
+        ) {
+            error(
+                format!("build_report.json couldn't be written: {}", err),
+                "[ BLOCK ]".to_string(),
+            );
+        }
+
+        for issue in self.block_registry.validate_textures() {
+            warn(issue.to_string(), "[ BLOCK ][ TEXTURE ]".to_string());
+        }
+
         self.generate_block_textures();
         self.generate_block_atlas();
         self.generate_terrain_atlas();
+        self.generate_flipbook_textures();
+
+        for issue in self.block_registry.validate_culling_rules() {
+            warn(issue.to_string(), "[ BLOCK ][ CULLING ]".to_string());
+        }
+        self.generate_block_culling_rules();
+    }
+
+    fn generate_texture_sets(&self) {
+        let folders = [
+            ("blocks", &self.block_texture_sets),
+            ("items", &self.item_texture_sets),
+        ];
+
+        for (folder, texture_sets) in folders {
+            let path = PathBuf::from(format!(
+                "./{RESULT_FOLDER}/packs/{}/RP/textures/{}",
+                &self.id, folder
+            ));
+
+            for texture_set in texture_sets {
+                if let Err(err) = texture_set.write(&path) {
+                    error(
+                        format!(
+                            "Texture set \"{}\" couldn't be written: {}",
+                            texture_set.name, err
+                        ),
+                        "[ TEXTURE SET ]".to_string(),
+                    );
+                }
+            }
+        }
     }
 
     fn generate_block_textures(&self) {
         let textures = self.block_registry.textures.clone();
 
         for texture in textures {
-            texture.src().build(PathBuf::from(format!(
+            let path = PathBuf::from(format!(
                 "./{RESULT_FOLDER}/packs/{}/RP/textures/blocks/{}.png",
                 &self.id,
                 texture.texture_name()
-            )));
+            ));
+            if let Some(parent) = path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+
+            texture.src().build(path);
         }
     }
 
@@ -542,12 +1434,94 @@ impl Pack {
         };
     }
 
+    fn generate_flipbook_textures(&self) {
+        if self.block_registry.flipbooks.is_empty() {
+            return;
+        }
+
+        if let Err(err) = fs::write(
+            format!(
+                "./{RESULT_FOLDER}/packs/{}/RP/textures/flipbook_textures.json",
+                &self.id
+            ),
+            serde_json::to_string_pretty(&serialize_flipbook_textures(
+                &self.block_registry.flipbooks,
+            ))
+            .unwrap(),
+        ) {
+            error(
+                format!("flipbook_textures.json couldn't be written: {}", err),
+                "[ BLOCK ][ TEXTURE ]".to_string(),
+            );
+        }
+    }
+
+    /// Registers PBR layers for the block texture of the same name. The RP
+    /// manifest gets the `pbr` capability once any texture set is registered.
+    pub fn register_block_texture_set(&mut self, texture_set: TextureSet) {
+        info(
+            format!("Registering Block Texture Set \"{}\"", texture_set.name),
+            "[ BLOCK ][ TEXTURE ]".to_string(),
+        );
+        self.block_texture_sets.push(texture_set);
+    }
+
+    fn generate_block_culling_rules(&self) {
+        if self.block_registry.culling_rules.is_empty() {
+            return;
+        }
+
+        let folder = format!("./{RESULT_FOLDER}/packs/{}/RP/block_culling", &self.id);
+        if let Err(err) = fs::create_dir_all(&folder) {
+            error(
+                format!("The block culling folder couldn't be created: {}", err),
+                "[ BLOCK ][ CULLING ]".to_string(),
+            );
+            return;
+        }
+        for culling_rules in &self.block_registry.culling_rules {
+            if let Err(err) = fs::write(
+                format!("{}/{}", folder, culling_rules.file_name()),
+                serde_json::to_string_pretty(&culling_rules.value()).unwrap(),
+            ) {
+                error(
+                    format!(
+                        "Block culling rules \"{}\" couldn't be written: {}",
+                        culling_rules.id.render(),
+                        err
+                    ),
+                    "[ BLOCK ][ CULLING ]".to_string(),
+                );
+            }
+        }
+    }
+
+    pub fn register_block_culling_rules(&mut self, culling_rules: BlockCullingRules) {
+        info(
+            format!(
+                "Registering Block Culling Rules \"{}\"",
+                culling_rules.id.render()
+            ),
+            "[ BLOCK ][ CULLING ]".to_string(),
+        );
+        self.block_registry.culling_rules.push(culling_rules);
+    }
+
     pub fn register_block(&mut self, block: Block) {
         self.block_registry.add_block(block.clone());
         info(
             format!("Registering block {}", block.type_id().render()),
             "[ BLOCK ]".to_string(),
         );
+        for texture in block.textures {
+            let registered = self.block_registry.textures.iter().any(|registered| {
+                registered.id().render() == texture.id().render()
+                    && registered.texture_name() == texture.texture_name()
+            });
+            if !registered {
+                self.register_block_texture(texture);
+            }
+        }
     }
 
     pub fn generate_localizations(&self) {
@@ -562,6 +1536,26 @@ impl Pack {
         self.localizations.push(localization)
     }
 
+    fn generate_assets(&self) {
+        for asset in self.assets.iter() {
+            let root = PathBuf::from(format!(
+                "./{RESULT_FOLDER}/packs/{}/{}",
+                &self.id,
+                match asset.part {
+                    PackPart::BP => "BP",
+                    PackPart::RP => "RP",
+                }
+            ));
+
+            if let Err(err) = asset.write(&root) {
+                error(
+                    format!("Cannot write asset \"{}\": {}", asset.path, err),
+                    "[ ASSET ]".to_string(),
+                );
+            }
+        }
+    }
+
     pub fn write_file(&self, pack_part: PackPart, path: impl Into<String>, contents: String) {
         let path = format!("./{RESULT_FOLDER}/packs/{}/{}/{}",
                            &self.id,
@@ -594,6 +1588,23 @@ impl Pack {
         )
     }
 
+    /// Whether the pack was created with `ScriptData`.
+    pub fn has_scripts(&self) -> bool {
+        self.scripts.is_some()
+    }
+
+    /// Adds a script addition to the pack's scripts. Additions need the pack
+    /// to be created with `ScriptData`.
+    pub fn add_script_addition(&mut self, addition: Arc<dyn ScriptAddition>) {
+        match &mut self.scripts {
+            Some(scripts) => scripts.additions.push(addition),
+            None => error(
+                "Cannot add a script addition to a pack without scripts".to_string(),
+                "[ SCRIPTS ]".to_string(),
+            ),
+        }
+    }
+
     pub fn generate_script_additions(&self) {
         let scripts = self.scripts.clone().unwrap().clone();
         let self_r = Arc::new(RwLock::new(&self));
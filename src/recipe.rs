@@ -1,9 +1,77 @@
-use crate::vio::{Buildable, Identifier};
+use crate::block::crafting_station::CraftingStation;
+use crate::block::Block;
+use crate::vio::{json_field, json_identifier, Buildable, Identifier, ParseError};
 use askama::Template;
+use serde_json::Value;
+use std::fmt;
+use std::sync::Arc;
 
 pub trait Recipe {
     fn serialize(&self) -> String;
     fn id(&self) -> Identifier;
+    /// The crafting tags of the stations the recipe is available in.
+    fn tags(&self) -> Vec<String>;
+}
+
+/// Recipe tags of the vanilla crafting stations.
+pub const VANILLA_RECIPE_TAGS: &[&str] = &[
+    "crafting_table",
+    "furnace",
+    "blast_furnace",
+    "smoker",
+    "campfire",
+    "soul_campfire",
+    "stonecutter",
+    "smithing_table",
+    "brewing_stand",
+    "cartography_table",
+    "material_reducer",
+    "compound_creator",
+    "lab_table",
+];
+
+/// A recipe tag no vanilla station or registered block provides, found by
+/// `validate_recipe_tags`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownRecipeTag {
+    pub recipe: String,
+    pub tag: String,
+}
+
+impl fmt::Display for UnknownRecipeTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Recipe \"{}\" has the tag \"{}\", which no vanilla station or registered block provides",
+            self.recipe, self.tag
+        )
+    }
+}
+
+/// Checks that every recipe tag is one of `VANILLA_RECIPE_TAGS` or a crafting
+/// tag of one of the blocks.
+pub fn validate_recipe_tags(
+    recipes: &[Arc<dyn Recipe>],
+    blocks: &[Block],
+) -> Vec<UnknownRecipeTag> {
+    let block_tags: Vec<String> = blocks.iter().flat_map(|block| block.crafting_tags()).collect();
+
+    recipes
+        .iter()
+        .flat_map(|recipe| {
+            recipe
+                .tags()
+                .into_iter()
+                .filter(|tag| {
+                    !VANILLA_RECIPE_TAGS.contains(&tag.as_str()) && !block_tags.contains(tag)
+                })
+                .map(|tag| UnknownRecipeTag {
+                    recipe: recipe.id().render(),
+                    tag,
+                })
+                .collect::<Vec<UnknownRecipeTag>>()
+        })
+        .collect()
 }
 
 #[derive(Clone)]
@@ -64,6 +132,70 @@ impl RecipeIO {
     }
 }
 
+impl RecipeIO {
+    /// Reads a recipe input or result, given either as an item identifier or
+    /// as an object with `item`/`tag`, `data` and `count`.
+    pub fn from_value(value: &Value) -> Result<Self, ParseError> {
+        if let Some(item) = value.as_str() {
+            return Identifier::parse(item)
+                .map(Self::new_typed)
+                .ok_or_else(|| invalid_value("item", value));
+        }
+
+        let mut io = if let Some(tag) = value.get("tag") {
+            Self::new_tagged(tag.as_str().ok_or_else(|| invalid_value("tag", tag))?)
+        } else {
+            let item = json_identifier(value, &["item"])?;
+            Self::new_typed(item)
+        };
+
+        if let Some(data) = value.get("data") {
+            io.data = Some(data.as_i64().ok_or_else(|| invalid_value("data", data))? as i32);
+        }
+        if let Some(count) = value.get("count") {
+            io.count = Some(
+                count
+                    .as_i64()
+                    .ok_or_else(|| invalid_value("count", count))? as i32,
+            );
+        }
+
+        Ok(io)
+    }
+}
+
+fn invalid_value(field: &str, value: &Value) -> ParseError {
+    ParseError::InvalidValue {
+        field: field.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn parse_tags(recipe: &Value) -> Result<Vec<String>, ParseError> {
+    match recipe.get("tags") {
+        Some(tags) => Ok(serde_json::from_value(tags.clone())?),
+        None => Ok(vec![]),
+    }
+}
+
+/// Reads an existing furnace, shapeless or shaped recipe.
+pub fn recipe_from_json(src: &str) -> Result<Arc<dyn Recipe>, ParseError> {
+    let value: Value = serde_json::from_str(src)?;
+
+    if value.get("minecraft:recipe_furnace").is_some() {
+        Ok(FurnaceRecipe::from_value(&value)?.build())
+    } else if value.get("minecraft:recipe_shapeless").is_some() {
+        Ok(ShapelessRecipe::from_value(&value)?.build())
+    } else if value.get("minecraft:recipe_shaped").is_some() {
+        Ok(ShapedRecipe::from_value(&value)?.build())
+    } else {
+        Err(ParseError::MissingField(
+            "minecraft:recipe_furnace | minecraft:recipe_shapeless | minecraft:recipe_shaped"
+                .to_string(),
+        ))
+    }
+}
+
 fn serialize_ingredients(ingredients: &Vec<RecipeIO>) -> String {
     let mut final_string = String::from("");
     for ingredient in ingredients {
@@ -154,9 +286,26 @@ impl Recipe for FurnaceRecipe {
     fn id(&self) -> Identifier {
         self.id.clone()
     }
+    fn tags(&self) -> Vec<String> {
+        self.tags.clone()
+    }
 }
 
 impl FurnaceRecipe {
+    pub fn from_value(value: &Value) -> Result<Self, ParseError> {
+        let recipe = json_field(value, &["minecraft:recipe_furnace"])?;
+
+        Ok(Self {
+            id: json_identifier(recipe, &["description", "identifier"])?,
+            tags: parse_tags(recipe)?,
+            input: RecipeIO::from_value(json_field(recipe, &["input"])?)?,
+            output: match RecipeIO::from_value(json_field(recipe, &["output"])?)?.item {
+                Some(output) => output,
+                None => return Err(invalid_value("output", &recipe["output"])),
+            },
+        })
+    }
+
     pub fn new(id: Identifier, input: RecipeIO, output: Identifier) -> Self {
         Self {
             id,
@@ -213,11 +362,31 @@ impl Recipe for ShapelessRecipe {
     fn id(&self) -> Identifier {
         self.id.clone()
     }
+    fn tags(&self) -> Vec<String> {
+        self.tags.clone()
+    }
 }
 
 impl Buildable for ShapelessRecipe {}
 
 impl ShapelessRecipe {
+    pub fn from_value(value: &Value) -> Result<Self, ParseError> {
+        let recipe = json_field(value, &["minecraft:recipe_shapeless"])?;
+        let ingredients = json_field(recipe, &["ingredients"])?
+            .as_array()
+            .ok_or_else(|| invalid_value("ingredients", &recipe["ingredients"]))?
+            .iter()
+            .map(RecipeIO::from_value)
+            .collect::<Result<Vec<RecipeIO>, ParseError>>()?;
+
+        Ok(Self {
+            id: json_identifier(recipe, &["description", "identifier"])?,
+            tags: parse_tags(recipe)?,
+            ingredients,
+            result: RecipeIO::from_value(json_field(recipe, &["result"])?)?,
+        })
+    }
+
     pub fn new(id: Identifier, result: RecipeIO) -> Self {
         Self {
             id,
@@ -235,6 +404,15 @@ impl ShapelessRecipe {
         cloned_self
     }
 
+    /// Adds the crafting tag of the station to the recipe's tags.
+    pub fn using_station(&mut self, station: &CraftingStation) -> Self {
+        let mut cloned_self = self.clone();
+
+        cloned_self.tags.push(station.tag());
+
+        cloned_self
+    }
+
     pub fn using_ingredients(&mut self, ingredients: Vec<RecipeIO>) -> Self {
         let mut cloned_self = self.clone();
 
@@ -283,9 +461,38 @@ impl Recipe for ShapedRecipe {
     fn id(&self) -> Identifier {
         self.id.clone()
     }
+    fn tags(&self) -> Vec<String> {
+        self.tags.clone()
+    }
 }
 
 impl ShapedRecipe {
+    pub fn from_value(value: &Value) -> Result<Self, ParseError> {
+        let recipe = json_field(value, &["minecraft:recipe_shaped"])?;
+        let keys = json_field(recipe, &["key"])?
+            .as_object()
+            .ok_or_else(|| invalid_value("key", &recipe["key"]))?;
+
+        let mut ingredients = vec![];
+        for (key, ingredient) in keys {
+            let mut chars = key.chars();
+            let key = match (chars.next(), chars.next()) {
+                (Some(key), None) => key,
+                _ => return Err(invalid_value("key", &Value::String(key.clone()))),
+            };
+
+            ingredients.push(RecipeIO::from_value(ingredient)?.using_key(key));
+        }
+
+        Ok(Self {
+            id: json_identifier(recipe, &["description", "identifier"])?,
+            tags: parse_tags(recipe)?,
+            ingredients,
+            result: RecipeIO::from_value(json_field(recipe, &["result"])?)?,
+            pattern: serde_json::from_value(json_field(recipe, &["pattern"])?.clone())?,
+        })
+    }
+
     pub fn new(id: Identifier, result: RecipeIO) -> Self {
         Self {
             id,
@@ -304,6 +511,15 @@ impl ShapedRecipe {
         self_cloned
     }
 
+    /// Adds the crafting tag of the station to the recipe's tags.
+    pub fn using_station(&mut self, station: &CraftingStation) -> Self {
+        let mut self_cloned = self.clone();
+
+        self_cloned.tags.push(station.tag());
+
+        self_cloned
+    }
+
     pub fn using_pattern(&mut self, pattern: Vec<impl Into<String> + Clone>) -> Self {
         let mut self_cloned: Self = self.clone();
 
@@ -0,0 +1,76 @@
+use crate::block::Block;
+use serde::Serialize;
+
+/// Limits the blocks of a pack are checked against when it's generated.
+#[derive(Clone, Debug)]
+pub struct PermutationLimits {
+    /// State combinations of a single block above which a warning is logged.
+    pub warn_block_state_combinations: u64,
+    /// State combinations of a single block above which it's not generated.
+    /// The game rejects blocks with more than 65,536.
+    pub max_block_state_combinations: u64,
+    /// State combinations of all blocks of the pack above which an error is
+    /// logged.
+    pub max_pack_state_combinations: u64,
+}
+
+impl Default for PermutationLimits {
+    fn default() -> Self {
+        Self {
+            warn_block_state_combinations: 4_096,
+            max_block_state_combinations: 65_536,
+            max_pack_state_combinations: 1_000_000,
+        }
+    }
+}
+
+/// Summary of a pack's blocks. The `build_report.json` written next to the
+/// BP and RP only covers the blocks that were generated.
+#[derive(Clone, Debug, Serialize)]
+pub struct BuildReport {
+    pub blocks: Vec<BlockReport>,
+    pub total_state_combinations: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct BlockReport {
+    pub block: String,
+    /// The cartesian size of the declared and trait states.
+    pub state_combinations: u64,
+    pub permutations: usize,
+    /// Conditions of permutations no state combination satisfies.
+    pub unmatchable_permutations: Vec<String>,
+}
+
+impl BlockReport {
+    pub fn new(block: &Block) -> Self {
+        Self {
+            block: block.type_id().render(),
+            state_combinations: block.state_combinations(),
+            permutations: block.permutations.len(),
+            unmatchable_permutations: block
+                .unmatchable_permutations()
+                .iter()
+                .map(|permutation| permutation.condition.clone())
+                .collect(),
+        }
+    }
+}
+
+impl BuildReport {
+    pub fn new(blocks: &[Block]) -> Self {
+        Self::from_reports(blocks.iter().map(BlockReport::new).collect())
+    }
+
+    /// Sums up reports already built for the blocks.
+    pub fn from_reports(blocks: Vec<BlockReport>) -> Self {
+        let total_state_combinations = blocks.iter().fold(0u64, |total, block| {
+            total.saturating_add(block.state_combinations)
+        });
+
+        Self {
+            blocks,
+            total_state_combinations,
+        }
+    }
+}
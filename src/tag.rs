@@ -0,0 +1,257 @@
+use crate::block::component::BlockTagComponent;
+use crate::block::utils::BlockDescriptor;
+use crate::block::Block;
+use crate::item::component::ItemTagsComponent;
+use crate::item::Item;
+use crate::vio::{Buildable, Identifier, MolangStatement};
+use std::fmt;
+
+/// Unnamespaced tags of vanilla blocks. Tags in the `minecraft` namespace are
+/// always taken to be vanilla.
+pub const VANILLA_BLOCK_TAGS: &[&str] = &[
+    "stone",
+    "metal",
+    "wood",
+    "log",
+    "dirt",
+    "sand",
+    "gravel",
+    "grass",
+    "snow",
+    "plant",
+    "pumpkin",
+    "rail",
+    "water",
+    "lava",
+    "mob_spawner",
+    "text_sign",
+    "trapdoors",
+    "fertilize_area",
+    "not_feature_replaceable",
+    "wood_pick_diggable",
+    "stone_pick_diggable",
+    "iron_pick_diggable",
+    "gold_pick_diggable",
+    "diamond_pick_diggable",
+];
+
+/// A block or item tag, e.g. `violin:marble` or `stone`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Tag(String);
+
+impl Tag {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_vanilla_block_tag(&self) -> bool {
+        self.0.starts_with("minecraft:") || VANILLA_BLOCK_TAGS.contains(&self.0.as_str())
+    }
+}
+
+impl From<&str> for Tag {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+/// Blocks matching a set of tags, rendered to Molang with `render`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TagQuery {
+    /// Blocks with at least one of the tags.
+    Any(Vec<Tag>),
+    /// Blocks with every tag.
+    All(Vec<Tag>),
+}
+
+impl TagQuery {
+    pub fn any(tags: Vec<impl Into<Tag>>) -> Self {
+        Self::Any(tags.into_iter().map(|tag| tag.into()).collect())
+    }
+
+    pub fn all(tags: Vec<impl Into<Tag>>) -> Self {
+        Self::All(tags.into_iter().map(|tag| tag.into()).collect())
+    }
+
+    pub fn tags(&self) -> &[Tag] {
+        match self {
+            Self::Any(tags) | Self::All(tags) => tags,
+        }
+    }
+
+    /// The query as Molang, e.g. `q.any_tag('stone', 'violin:marble')`.
+    pub fn render(&self) -> String {
+        let query = match self {
+            Self::Any(_) => "q.any_tag",
+            Self::All(_) => "q.all_tags",
+        };
+        let tags = self
+            .tags()
+            .iter()
+            .map(|tag| format!("'{}'", tag.name()))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        format!("{}({})", query, tags)
+    }
+
+    pub fn block_descriptor(&self) -> BlockDescriptor {
+        BlockDescriptor::new_tags(MolangStatement::new(self.render()))
+    }
+}
+
+/// Tags of a pack's blocks and items, added to their components when the
+/// pack is generated. Block descriptors built through `block_descriptor`
+/// are checked against the tags the blocks end up with.
+#[derive(Clone, Default)]
+pub struct TagRegistry {
+    pub block_tags: Vec<(Identifier, Vec<Tag>)>,
+    pub item_tags: Vec<(Identifier, Vec<Tag>)>,
+    pub queries: Vec<TagQuery>,
+}
+
+impl TagRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tag_block(&mut self, block: Identifier, tags: Vec<Tag>) {
+        add_tags(&mut self.block_tags, block, tags);
+    }
+
+    pub fn tag_item(&mut self, item: Identifier, tags: Vec<Tag>) {
+        add_tags(&mut self.item_tags, item, tags);
+    }
+
+    pub fn block_tags_of(&self, block: &Identifier) -> Vec<Tag> {
+        tags_of(&self.block_tags, block)
+    }
+
+    pub fn item_tags_of(&self, item: &Identifier) -> Vec<Tag> {
+        tags_of(&self.item_tags, item)
+    }
+
+    /// A block descriptor for the blocks matching the query, checked by
+    /// `validate`.
+    pub fn block_descriptor(&mut self, query: TagQuery) -> BlockDescriptor {
+        let descriptor = query.block_descriptor();
+        self.queries.push(query);
+
+        descriptor
+    }
+
+    /// The block with a `tag:` component for each of its registered tags it
+    /// doesn't have yet.
+    pub fn apply_to_block(&self, block: &Block) -> Block {
+        let existing = block.tags();
+        let mut components = block.components.clone();
+        for tag in self.block_tags_of(&block.type_id()) {
+            if !existing.contains(&tag) {
+                components.push(BlockTagComponent::new(tag).build());
+            }
+        }
+
+        block.clone().using_components(components)
+    }
+
+    /// The item with its registered tags merged into its `minecraft:tags`
+    /// component.
+    pub fn apply_to_item(&self, item: &Item) -> Item {
+        let registered = self.item_tags_of(&item.type_id);
+        if registered.is_empty() {
+            return item.clone();
+        }
+
+        let mut tags = item.tags();
+        for tag in registered {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        let mut components: Vec<_> = item
+            .components
+            .iter()
+            .filter(|component| component.id() != "minecraft:tags")
+            .cloned()
+            .collect();
+        components.push(ItemTagsComponent::from_tags(&tags).build());
+
+        item.clone().using_components(components)
+    }
+
+    /// Finds the tags of the queries that are neither vanilla nor on any of
+    /// the blocks, registered or declared with `tag:` components.
+    pub fn validate(&self, blocks: &[Block]) -> Vec<UndefinedTag> {
+        let mut defined: Vec<Tag> = self
+            .block_tags
+            .iter()
+            .flat_map(|(_, tags)| tags.iter().cloned())
+            .collect();
+        defined.extend(blocks.iter().flat_map(|block| block.tags()));
+
+        let mut undefined: Vec<UndefinedTag> = vec![];
+        for query in &self.queries {
+            for tag in query.tags() {
+                let issue = UndefinedTag {
+                    query: query.render(),
+                    tag: tag.clone(),
+                };
+                if !tag.is_vanilla_block_tag()
+                    && !defined.contains(tag)
+                    && !undefined.contains(&issue)
+                {
+                    undefined.push(issue);
+                }
+            }
+        }
+
+        undefined
+    }
+}
+
+fn add_tags(registered: &mut Vec<(Identifier, Vec<Tag>)>, id: Identifier, tags: Vec<Tag>) {
+    match registered
+        .iter_mut()
+        .find(|(other, _)| other.render() == id.render())
+    {
+        Some((_, existing)) => {
+            for tag in tags {
+                if !existing.contains(&tag) {
+                    existing.push(tag);
+                }
+            }
+        }
+        None => registered.push((id, tags)),
+    }
+}
+
+fn tags_of(registered: &[(Identifier, Vec<Tag>)], id: &Identifier) -> Vec<Tag> {
+    registered
+        .iter()
+        .find(|(other, _)| other.render() == id.render())
+        .map(|(_, tags)| tags.clone())
+        .unwrap_or_default()
+}
+
+/// A tag a block descriptor queries that no block has, found by
+/// `TagRegistry::validate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UndefinedTag {
+    pub query: String,
+    pub tag: Tag,
+}
+
+impl fmt::Display for UndefinedTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\"{}\" queries the tag \"{}\", which isn't vanilla or on any block",
+            self.query,
+            self.tag.name()
+        )
+    }
+}
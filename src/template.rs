@@ -24,4 +24,5 @@ pub struct RpManifestTemplate<'a> {
     pub uuid_1: &'a str,
     pub uuid_2: &'a str,
     pub version: &'a str,
+    pub pbr: &'a bool,
 }
@@ -0,0 +1,256 @@
+use crate::image::{Channel, Image};
+use crate::vio::RGBColor;
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::Path;
+
+/// The color layer of a texture set.
+#[derive(Clone)]
+pub enum TextureSetColor {
+    /// The texture of the same name, registered as a block or item texture.
+    Registered,
+    Image(Image),
+    Uniform(RGBColor),
+}
+
+/// The metalness, emissive, roughness and optionally subsurface layer of a
+/// texture set.
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum MerLayer {
+    /// An image with metalness, emissive and roughness in red, green and
+    /// blue.
+    Image(Image),
+    /// Like `Image`, with subsurface in alpha.
+    ImageWithSubsurface(Image),
+    /// Separate grayscale images or uniform values, packed into one image
+    /// unless they're all uniform.
+    Channels {
+        metalness: Channel,
+        emissive: Channel,
+        roughness: Channel,
+        subsurface: Option<Channel>,
+    },
+}
+
+impl MerLayer {
+    pub fn new(metalness: Channel, emissive: Channel, roughness: Channel) -> Self {
+        Self::Channels {
+            metalness,
+            emissive,
+            roughness,
+            subsurface: None,
+        }
+    }
+
+    pub fn new_with_subsurface(
+        metalness: Channel,
+        emissive: Channel,
+        roughness: Channel,
+        subsurface: Channel,
+    ) -> Self {
+        Self::Channels {
+            metalness,
+            emissive,
+            roughness,
+            subsurface: Some(subsurface),
+        }
+    }
+
+    fn has_subsurface(&self) -> bool {
+        matches!(
+            self,
+            Self::ImageWithSubsurface(_)
+                | Self::Channels {
+                    subsurface: Some(_),
+                    ..
+                }
+        )
+    }
+}
+
+/// The layer giving the surface its depth.
+#[derive(Clone)]
+pub enum DepthLayer {
+    Normal(Image),
+    Heightmap(Image),
+}
+
+/// A `*.texture_set.json` adding PBR layers to the block or item texture of
+/// the same name.
+#[derive(Clone)]
+pub struct TextureSet {
+    /// The file name of the texture, e.g. `violin_marble`.
+    pub name: String,
+    pub color: TextureSetColor,
+    pub mer: Option<MerLayer>,
+    pub depth: Option<DepthLayer>,
+}
+
+impl TextureSet {
+    pub fn new(name: impl Into<String>, color: TextureSetColor) -> Self {
+        Self {
+            name: name.into(),
+            color,
+            mer: None,
+            depth: None,
+        }
+    }
+
+    pub fn using_mer(self, mer: MerLayer) -> Self {
+        Self {
+            mer: Some(mer),
+            ..self
+        }
+    }
+
+    pub fn using_normal(self, normal: Image) -> Self {
+        Self {
+            depth: Some(DepthLayer::Normal(normal)),
+            ..self
+        }
+    }
+
+    pub fn using_heightmap(self, heightmap: Image) -> Self {
+        Self {
+            depth: Some(DepthLayer::Heightmap(heightmap)),
+            ..self
+        }
+    }
+
+    fn mer_name(&self) -> String {
+        match &self.mer {
+            Some(mer) if mer.has_subsurface() => format!("{}_mers", self.name),
+            _ => format!("{}_mer", self.name),
+        }
+    }
+
+    /// The images the texture set writes next to it, by file name without
+    /// extension. Returns `None` if MER channels can't be packed because
+    /// their images differ in size.
+    pub fn images(&self) -> Option<Vec<(String, Image)>> {
+        let mut images = vec![];
+
+        if let TextureSetColor::Image(image) = &self.color {
+            images.push((self.name.clone(), image.clone()));
+        }
+
+        match &self.mer {
+            Some(MerLayer::Image(image)) | Some(MerLayer::ImageWithSubsurface(image)) => {
+                images.push((self.mer_name(), image.clone()));
+            }
+            Some(MerLayer::Channels {
+                metalness,
+                emissive,
+                roughness,
+                subsurface,
+            }) if [metalness, emissive, roughness]
+                .into_iter()
+                .chain(subsurface)
+                .any(|channel| matches!(channel, Channel::Image(_))) =>
+            {
+                let opaque = Channel::Value(255);
+                let alpha = subsurface.as_ref().unwrap_or(&opaque);
+                let image = Image::from_channels([metalness, emissive, roughness, alpha])?;
+                images.push((self.mer_name(), image));
+            }
+            _ => {}
+        }
+
+        match &self.depth {
+            Some(DepthLayer::Normal(image)) => {
+                images.push((format!("{}_normal", self.name), image.clone()))
+            }
+            Some(DepthLayer::Heightmap(image)) => {
+                images.push((format!("{}_heightmap", self.name), image.clone()))
+            }
+            None => {}
+        }
+
+        Some(images)
+    }
+
+    pub fn value(&self) -> Value {
+        let mut texture_set = Map::new();
+
+        texture_set.insert(
+            "color".to_string(),
+            match &self.color {
+                TextureSetColor::Registered | TextureSetColor::Image(_) => json!(self.name),
+                TextureSetColor::Uniform(color) => json!(color.render_as_hex()),
+            },
+        );
+
+        if let Some(mer) = &self.mer {
+            let key = if mer.has_subsurface() {
+                "metalness_emissive_roughness_subsurface"
+            } else {
+                "metalness_emissive_roughness"
+            };
+            let uniform = match mer {
+                MerLayer::Channels {
+                    metalness: Channel::Value(metalness),
+                    emissive: Channel::Value(emissive),
+                    roughness: Channel::Value(roughness),
+                    subsurface,
+                } => match subsurface {
+                    Some(Channel::Value(subsurface)) => {
+                        Some(json!([metalness, emissive, roughness, subsurface]))
+                    }
+                    Some(Channel::Image(_)) => None,
+                    None => Some(json!([metalness, emissive, roughness])),
+                },
+                _ => None,
+            };
+
+            texture_set.insert(
+                key.to_string(),
+                uniform.unwrap_or_else(|| json!(self.mer_name())),
+            );
+        }
+
+        match &self.depth {
+            Some(DepthLayer::Normal(_)) => {
+                texture_set.insert("normal".to_string(), json!(format!("{}_normal", self.name)));
+            }
+            Some(DepthLayer::Heightmap(_)) => {
+                texture_set.insert(
+                    "heightmap".to_string(),
+                    json!(format!("{}_heightmap", self.name)),
+                );
+            }
+            None => {}
+        }
+
+        let format_version = match &self.mer {
+            Some(mer) if mer.has_subsurface() => "1.21.30",
+            _ => "1.16.100",
+        };
+
+        json!({
+            "format_version": format_version,
+            "minecraft:texture_set": texture_set
+        })
+    }
+
+    /// Writes the texture set and its images into the given texture folder,
+    /// e.g. `RP/textures/blocks`.
+    pub fn write(&self, folder: &Path) -> std::io::Result<()> {
+        let images = self.images().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("The MER images of \"{}\" differ in size", self.name),
+            )
+        })?;
+
+        fs::create_dir_all(folder)?;
+        for (name, image) in images {
+            image.build(folder.join(format!("{}.png", name)));
+        }
+
+        fs::write(
+            folder.join(format!("{}.texture_set.json", self.name)),
+            serde_json::to_string_pretty(&self.value())?,
+        )
+    }
+}
@@ -1,6 +1,9 @@
 use derive_setters::Setters;
+use serde::de::Error as _;
 use serde::ser::SerializeSeq;
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::fmt;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -32,6 +35,17 @@ impl Serialize for Vec3 {
     }
 }
 
+impl<'de> Deserialize<'de> for Vec3 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let [x, y, z] = <[f64; 3]>::deserialize(deserializer)?;
+
+        Ok(Self { x, y, z })
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Identifier {
     pub namespace: String,
@@ -49,6 +63,17 @@ impl Identifier {
             value: value.into(),
         }
     }
+
+    /// Parses a `namespace:value` identifier.
+    pub fn parse(src: &str) -> Option<Self> {
+        let (namespace, value) = src.split_once(':')?;
+
+        if namespace.is_empty() || value.is_empty() {
+            return None;
+        }
+
+        Some(Self::new(namespace, value))
+    }
 }
 
 impl From<(String, String)> for Identifier {
@@ -72,6 +97,18 @@ impl Serialize for Identifier {
     }
 }
 
+impl<'de> Deserialize<'de> for Identifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let src = String::deserialize(deserializer)?;
+
+        Identifier::parse(&src)
+            .ok_or_else(|| D::Error::custom(format!("invalid identifier \"{}\"", src)))
+    }
+}
+
 #[derive(Clone)]
 pub struct Pair<T: Clone, K: Clone> {
     pub first: T,
@@ -94,7 +131,141 @@ pub trait Generatable {
     fn generate(&self, path_buf: impl Into<PathBuf>);
 }
 
-#[derive(Clone)]
+/// Returned when an item, block or permutation has two components with the same key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateComponentError {
+    pub component: String,
+}
+
+impl fmt::Display for DuplicateComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "component \"{}\" is defined more than once",
+            self.component
+        )
+    }
+}
+
+impl std::error::Error for DuplicateComponentError {}
+
+/// Returned when existing JSON can't be read into Violin types.
+#[derive(Debug)]
+pub enum ParseError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    MissingField(String),
+    InvalidValue { field: String, value: String },
+    Duplicate(DuplicateComponentError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Io(err) => write!(f, "{}", err),
+            ParseError::Json(err) => write!(f, "invalid JSON: {}", err),
+            ParseError::MissingField(field) => write!(f, "missing field \"{}\"", field),
+            ParseError::InvalidValue { field, value } => {
+                write!(f, "invalid value {} for \"{}\"", value, field)
+            }
+            ParseError::Duplicate(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<std::io::Error> for ParseError {
+    fn from(value: std::io::Error) -> Self {
+        ParseError::Io(value)
+    }
+}
+
+impl From<serde_json::Error> for ParseError {
+    fn from(value: serde_json::Error) -> Self {
+        ParseError::Json(value)
+    }
+}
+
+impl From<DuplicateComponentError> for ParseError {
+    fn from(value: DuplicateComponentError) -> Self {
+        ParseError::Duplicate(value)
+    }
+}
+
+/// Looks up `path` (e.g. `["minecraft:item", "description"]`) in `value`.
+pub(crate) fn json_field<'a>(value: &'a Value, path: &[&str]) -> Result<&'a Value, ParseError> {
+    let mut current = value;
+
+    for key in path {
+        current = current
+            .get(key)
+            .ok_or_else(|| ParseError::MissingField(path.join(".")))?;
+    }
+
+    Ok(current)
+}
+
+pub(crate) fn json_identifier(value: &Value, path: &[&str]) -> Result<Identifier, ParseError> {
+    let field = json_field(value, path)?;
+
+    field
+        .as_str()
+        .and_then(Identifier::parse)
+        .ok_or_else(|| ParseError::InvalidValue {
+            field: path.join("."),
+            value: field.to_string(),
+        })
+}
+
+pub(crate) fn json_format_version(value: &Value) -> Result<SemVer, ParseError> {
+    let field = json_field(value, &["format_version"])?;
+
+    field
+        .as_str()
+        .and_then(SemVer::parse)
+        .ok_or_else(|| ParseError::InvalidValue {
+            field: "format_version".to_string(),
+            value: field.to_string(),
+        })
+}
+
+/// Whether two JSON values describe the same data. Numbers are compared by
+/// value (`1` equals `1.0`) and a `null` field equals a missing one.
+pub(crate) fn json_equivalent(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_equivalent(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => a.keys().chain(b.keys()).all(|key| {
+            json_equivalent(
+                a.get(key).unwrap_or(&Value::Null),
+                b.get(key).unwrap_or(&Value::Null),
+            )
+        }),
+        (a, b) => a == b,
+    }
+}
+
+/// Collects `(key, value)` component pairs into a JSON object, failing on the
+/// first key that appears twice.
+pub(crate) fn components_to_map(
+    components: impl IntoIterator<Item = (String, Value)>,
+) -> Result<Map<String, Value>, DuplicateComponentError> {
+    let mut map = Map::new();
+
+    for (id, value) in components {
+        if map.contains_key(&id) {
+            return Err(DuplicateComponentError { component: id });
+        }
+        map.insert(id, value);
+    }
+
+    Ok(map)
+}
+
+#[derive(Clone, Debug)]
 pub struct SemVer {
     major: i32,
     minor: i32,
@@ -131,6 +302,33 @@ impl SemVer {
         )
     }
 
+    /// Parses `major.minor.patch`, with an optional `-beta` suffix. A missing
+    /// patch (`1.12`) is read as `0`.
+    pub fn parse(src: &str) -> Option<Self> {
+        let (version, beta) = match src.strip_suffix("-beta") {
+            Some(version) => (version, true),
+            None => (src, false),
+        };
+        let mut parts = version.split('.').map(|part| part.parse::<i32>());
+
+        let major = parts.next()?.ok()?;
+        let minor = parts.next()?.ok()?;
+        let patch = match parts.next() {
+            Some(patch) => patch.ok()?,
+            None => 0,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+            beta,
+        })
+    }
+
     pub fn render_commas(&self) -> String {
         format!("{}, {}, {}", self.major, self.minor, self.patch,)
     }
@@ -209,7 +407,7 @@ impl MolangStatement {
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[derive(Default)]
 pub enum ColorCode {
@@ -313,7 +511,7 @@ impl RGBColor {
     }
 
     fn component_to_string(v: u8) -> String {
-        format!("{:X}", v)
+        format!("{:02X}", v)
     }
 }
 
@@ -337,6 +535,37 @@ impl Serialize for RGBColor {
     }
 }
 
+impl<'de> Deserialize<'de> for RGBColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::String(hex) => {
+                let digits = hex.strip_prefix('#').unwrap_or(&hex);
+                let channel = |idx: usize| {
+                    digits
+                        .get(idx..idx + 2)
+                        .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+                };
+
+                match (digits.len(), channel(0), channel(2), channel(4)) {
+                    (6, Some(red), Some(green), Some(blue)) => Ok(Self {
+                        preferred_serialization_way: ColorSerializationWay::Hex,
+                        ..Self::new(red, green, blue)
+                    }),
+                    _ => Err(D::Error::custom(format!("invalid hex color \"{}\"", hex))),
+                }
+            }
+            value => {
+                let [red, green, blue] = <[u8; 3]>::deserialize(value).map_err(D::Error::custom)?;
+
+                Ok(Self::new(red, green, blue))
+            }
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum ColorSerializationWay {
     Hex,
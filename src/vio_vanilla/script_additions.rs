@@ -3,11 +3,13 @@ use std::fs;
 use std::sync::{Arc, RwLock};
 use askama::Template;
 use viola::ViolaDefault;
+use crate::block::building_set::{BlockPreset, BuildingSet};
 use crate::pack::{Pack, PackPart};
 use crate::script::ScriptAddition;
-use crate::vio::Buildable;
+use crate::vio::{Buildable, Identifier};
 
 const COMMAND_PATH: &str = "viogen_commands";
+const CONSUME_EFFECTS_PATH: &str = "viogen_consume_effects";
 
 #[derive(Clone)]
 pub enum CustomCommandArg {
@@ -108,4 +110,172 @@ impl CustomCommand {
             args
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Clone)]
+pub struct ConsumeEffect {
+    pub effect: String,
+    pub duration: i32,
+    pub amplifier: u8,
+    pub chance: f64,
+}
+
+impl ConsumeEffect {
+    /// An effect applied for `duration` ticks.
+    pub fn new(effect: impl Into<String>, duration: i32, amplifier: u8) -> Self {
+        Self {
+            effect: effect.into(),
+            duration,
+            amplifier,
+            chance: 1.0,
+        }
+    }
+
+    pub fn using_chance(self, chance: f64) -> Self {
+        Self { chance, ..self }
+    }
+}
+
+/// Registers an item custom component that applies effects to the entity
+/// consuming the item.
+#[derive(Clone)]
+pub struct ConsumeEffects {
+    pub component_id: Identifier,
+    pub effects: Vec<ConsumeEffect>,
+}
+
+impl Buildable for ConsumeEffects {}
+
+#[derive(Template)]
+#[template(
+    path = "scripts/consume_effects.js.jinja2",
+    escape = "none"
+)]
+struct ConsumeEffectsTemplate {
+    component_id: String,
+    effects: String,
+}
+
+impl ConsumeEffects {
+    pub fn new(component_id: Identifier, effects: Vec<ConsumeEffect>) -> Self {
+        Self {
+            component_id,
+            effects,
+        }
+    }
+
+    fn file_name(&self) -> String {
+        self.component_id.render().replace(':', "_")
+    }
+
+    /// The script registering the custom component.
+    pub fn script(&self) -> String {
+        ConsumeEffectsTemplate {
+            component_id: self.component_id.render(),
+            effects: self.effects.iter().map(|effect| {
+                let add_effect = format!(
+                    "source.addEffect(\"{}\", {}, {{ amplifier: {} }});",
+                    effect.effect, effect.duration, effect.amplifier
+                );
+
+                if effect.chance < 1.0 {
+                    format!("            if (Math.random() < {}) {}", effect.chance, add_effect)
+                } else {
+                    format!("            {}", add_effect)
+                }
+            }).collect::<Vec<String>>().join("\n"),
+        }.render().unwrap()
+    }
+}
+
+impl ScriptAddition for ConsumeEffects {
+    fn build_addition(&self, pack: Arc<RwLock<&&Pack>>) {
+        let mut main = pack.read().unwrap()
+            .read_file(PackPart::BP, "scripts/main.js")
+            .unwrap();
+
+        main.push_str(
+            &format!("\nimport \"./{}/{}.js\"", CONSUME_EFFECTS_PATH, self.file_name())
+        );
+
+        pack.read().unwrap()
+            .write_file(PackPart::BP, "scripts/main.js", main);
+
+        pack.read().unwrap()
+            .write_file(PackPart::BP, format!("scripts/{}/{}.js", CONSUME_EFFECTS_PATH, self.file_name()), self.script());
+    }
+}
+
+const BUILDING_SET_PATH: &str = "viogen_building_sets";
+
+/// Registers the block custom components a `BuildingSet` uses: stacking
+/// slabs, opening trapdoors and doors, and placing and breaking both halves
+/// of doors.
+#[derive(Clone)]
+pub struct BuildingSetComponents {
+    pub set: BuildingSet,
+}
+
+impl Buildable for BuildingSetComponents {}
+
+#[derive(Template)]
+#[template(
+    path = "scripts/building_set.js.jinja2",
+    escape = "none"
+)]
+struct BuildingSetTemplate {
+    slab: String,
+    trapdoor: String,
+    door: String,
+    double_state: String,
+    open_state: String,
+    upper_state: String,
+}
+
+impl BuildingSetComponents {
+    pub fn new(set: BuildingSet) -> Self {
+        Self { set }
+    }
+
+    fn file_name(&self) -> String {
+        format!("{}_{}", self.set.namespace, self.set.material)
+    }
+
+    /// The component id of the preset, the id of its block, if the set has
+    /// it, an empty string otherwise.
+    fn component_id(&self, preset: BlockPreset) -> String {
+        if self.set.presets.contains(&preset) {
+            self.set.block_id(preset).render()
+        } else {
+            String::new()
+        }
+    }
+}
+
+impl ScriptAddition for BuildingSetComponents {
+    fn build_addition(&self, pack: Arc<RwLock<&&Pack>>) {
+        let mut main = pack.read().unwrap()
+            .read_file(PackPart::BP, "scripts/main.js")
+            .unwrap();
+
+        main.push_str(
+            &format!("\nimport \"./{}/{}.js\"", BUILDING_SET_PATH, self.file_name())
+        );
+
+        pack.read().unwrap()
+            .write_file(PackPart::BP, "scripts/main.js", main);
+
+        let state = |name: &str| Identifier::new(&self.set.namespace, name).render();
+        let template = BuildingSetTemplate {
+            slab: self.component_id(BlockPreset::Slab),
+            trapdoor: self.component_id(BlockPreset::Trapdoor),
+            door: self.component_id(BlockPreset::Door),
+            double_state: state("double"),
+            open_state: state("open"),
+            upper_state: state("upper_block"),
+        }.render().unwrap();
+
+        pack.read().unwrap()
+            .write_file(PackPart::BP, format!("scripts/{}/{}.js", BUILDING_SET_PATH, self.file_name()), template);
+    }
+}